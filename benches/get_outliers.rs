@@ -0,0 +1,59 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use outliers::OutlierIdentifier;
+
+// A reproducible data set of roughly uniform values with a sprinkling of extreme ones, generated
+// with a linear congruential generator so that no random number crate is needed
+fn data_set(len: usize) -> Vec<f64> {
+    let mut state: u64 = 0x853c_49e6_748f_ea9b;
+
+    (0..len)
+        .map(|index| {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let value = (state >> 11) as f64 / (1u64 << 53) as f64;
+
+            if index % 1_000 == 0 {
+                value * 1_000.0
+            } else {
+                value
+            }
+        })
+        .collect()
+}
+
+// Run with `cargo bench` and `cargo bench --features rayon` to compare the sequential and parallel
+// sorting and partitioning.  Each `OutlierIdentifier` is built in the setup closure of
+// `iter_batched()`, so cloning the data set isn't part of the measured time, only the detection.
+fn get_outliers(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("get_outliers");
+    group.sample_size(10);
+
+    for len in [100_000, 1_000_000, 10_000_000] {
+        let unsorted_data_set = data_set(len);
+        let mut sorted_data_set = unsorted_data_set.clone();
+        sorted_data_set.sort_by(f64::total_cmp);
+
+        for (name, data_set, data_is_sorted) in [
+            ("unsorted", &unsorted_data_set, false),
+            ("sorted", &sorted_data_set, true),
+        ] {
+            group.bench_with_input(
+                BenchmarkId::new(name, len),
+                data_set,
+                |bencher, data_set| {
+                    bencher.iter_batched(
+                        || OutlierIdentifier::new(data_set.clone(), data_is_sorted),
+                        |outlier_identifier| outlier_identifier.get_outliers(),
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, get_outliers);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+use crate::{OutlierError, OutlierIdentifier};
+
+/// Removes every outlier from `data_set` in place, using `k_value`, keeping the remaining values in
+/// their original order, such as for cleaning data before further processing.  The fences are
+/// computed from a sorted copy of `data_set` before anything is removed.  `retain_non_outliers()`
+/// will return an `Err`, leaving `data_set` untouched, if `data_set` contains one or more `NAN`s or
+/// infinities, or if `k_value` is negative.
+pub fn retain_non_outliers(data_set: &mut Vec<f64>, k_value: f64) -> Result<(), OutlierError> {
+    let (lower_fence, upper_fence) = OutlierIdentifier::from_slice(data_set, false)
+        .with_k_value(k_value)
+        .get_fences()?;
+
+    data_set.retain(|&data| lower_fence <= data && data <= upper_fence);
+
+    Ok(())
+}
+
+#[test]
+fn retain_non_outliers_1() {
+    let mut data = [
+        14.0, 10.0, 22.0, 12.0, 11.0, -40.0, 15.0, 11.0, 17.0, 13.0, 12.0, 14.0,
+    ]
+    .to_vec();
+    retain_non_outliers(&mut data, 1.5).unwrap();
+
+    assert_eq!(
+        data,
+        [14.0, 10.0, 12.0, 11.0, 15.0, 11.0, 17.0, 13.0, 12.0, 14.0].to_vec()
+    );
+}
+
+#[test]
+fn retain_non_outliers_error_leaves_data_set_untouched() {
+    let mut data = [1.0, 2.0, 100.0, f64::NAN].to_vec();
+    let result = retain_non_outliers(&mut data, 1.5);
+
+    assert!(matches!(result, Err(OutlierError::ContainsNans)));
+    assert_eq!(data.len(), 4);
+    assert_eq!(data[..3], [1.0, 2.0, 100.0]);
+}
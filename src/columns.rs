@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use crate::{OutlierError, OutlierIdentifier, OutlierResult};
+
+/// Performs the outlier identification independently on every numeric column of delimited text,
+/// such as a CSV file, using `k_value`.  The returned map holds the results of each numeric column,
+/// keyed by the column's index.  A column is numeric if every one of its fields parses as an `f64`;
+/// all other columns are skipped.  If `has_header` is `true`, the first row is a header, and is
+/// ignored; otherwise, it is data like any other row, so a text field in it makes its column
+/// non-numeric.  Blank lines are ignored and quoted fields aren't supported.
+/// `detect_all_columns()` will return an `Err` if the reader fails, if the rows don't all have the
+/// same number of fields, or if a numeric column contains one or more `NAN`s or `k_value` is
+/// negative.
+pub fn detect_all_columns<R: BufRead>(
+    reader: R,
+    delimiter: u8,
+    has_header: bool,
+    k_value: f64,
+) -> Result<HashMap<usize, OutlierResult>, OutlierError> {
+    let delimiter = char::from(delimiter);
+    let mut columns: Vec<Option<Vec<f64>>> = Vec::new();
+    let mut is_first_row = true;
+
+    for (row_index, line) in reader.lines().enumerate() {
+        let line = line.map_err(|error| OutlierError::ReadError(error.to_string()))?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields: Vec<Option<f64>> = line
+            .split(delimiter)
+            .map(|field| field.trim().parse().ok())
+            .collect();
+
+        if is_first_row {
+            is_first_row = false;
+            columns = vec![Some(Vec::new()); fields.len()];
+
+            if has_header {
+                continue;
+            }
+        }
+
+        if fields.len() != columns.len() {
+            return Err(OutlierError::InconsistentRowLength {
+                row: row_index,
+                expected: columns.len(),
+                found: fields.len(),
+            });
+        }
+
+        for (column, field) in columns.iter_mut().zip(fields) {
+            match field {
+                Some(value) => {
+                    if let Some(column) = column {
+                        column.push(value);
+                    }
+                }
+                None => *column = None,
+            }
+        }
+    }
+
+    let mut results = HashMap::new();
+
+    for (column_index, column) in columns.into_iter().enumerate() {
+        if let Some(column) = column {
+            let outlier_result = OutlierIdentifier::new(column, false)
+                .with_k_value(k_value)
+                .get_outliers()?
+                .into();
+
+            results.insert(column_index, outlier_result);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Performs the outlier identification independently on every column of `matrix`, whose rows are
+/// observations and whose columns are features, using `k_value`.  One partition tuple is returned
+/// per column, in column order, as `get_outliers()` would return it.  `outliers_per_column()` will
+/// return an `Err` if the rows don't all have the same length, or if a column contains one or more
+/// `NAN`s or `k_value` is negative.
+#[allow(clippy::type_complexity)]
+pub fn outliers_per_column(
+    matrix: &[Vec<f64>],
+    k_value: f64,
+) -> Result<Vec<(Vec<f64>, Vec<f64>, Vec<f64>)>, OutlierError> {
+    let column_count = matrix.first().map_or(0, Vec::len);
+
+    for (row_index, row) in matrix.iter().enumerate() {
+        if row.len() != column_count {
+            return Err(OutlierError::InconsistentRowLength {
+                row: row_index,
+                expected: column_count,
+                found: row.len(),
+            });
+        }
+    }
+
+    (0..column_count)
+        .map(|column_index| {
+            let column = matrix.iter().map(|row| row[column_index]).collect();
+
+            OutlierIdentifier::new(column, false)
+                .with_k_value(k_value)
+                .get_outliers()
+        })
+        .collect()
+}
+
+#[test]
+fn detect_all_columns_skips_non_numeric_columns() {
+    let csv = "id,name,latency\n\
+               1,a,10.0\n\
+               2,b,12.0\n\
+               3,c,11.0\n\
+               4,d,13.0\n\
+               5,e,95.0\n";
+    let results = detect_all_columns(csv.as_bytes(), b',', true, 1.5).unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert!(!results.contains_key(&1));
+    assert_eq!(results[&0].upper, [].to_vec());
+    assert_eq!(results[&2].upper, [95.0].to_vec());
+}
+
+#[test]
+fn detect_all_columns_without_header() {
+    let csv = "1,a,10.0\n\
+               2,b,12.0\n\
+               3,c,11.0\n\
+               4,d,13.0\n\
+               5,e,95.0\n";
+    let results = detect_all_columns(csv.as_bytes(), b',', false, 1.5).unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[&0].non_outliers, [1.0, 2.0, 3.0, 4.0, 5.0].to_vec());
+    assert_eq!(results[&2].upper, [95.0].to_vec());
+}
+
+#[test]
+fn detect_all_columns_inconsistent_row_length_error() {
+    let csv = "1\t2\n3\t4\n5\n";
+    let results = detect_all_columns(csv.as_bytes(), b'\t', false, 1.5);
+
+    assert!(matches!(
+        results,
+        Err(OutlierError::InconsistentRowLength {
+            row: 2,
+            expected: 2,
+            found: 1
+        })
+    ));
+}
+
+#[test]
+fn outliers_per_column_1() {
+    let matrix = [
+        [1.0, 10.0].to_vec(),
+        [2.0, 12.0].to_vec(),
+        [3.0, 11.0].to_vec(),
+        [4.0, 13.0].to_vec(),
+        [5.0, 95.0].to_vec(),
+    ];
+    let results = outliers_per_column(&matrix, 1.5).unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].1, [1.0, 2.0, 3.0, 4.0, 5.0].to_vec());
+    assert_eq!(results[1].2, [95.0].to_vec());
+}
+
+#[test]
+fn outliers_per_column_inconsistent_row_length_error() {
+    let matrix = [[1.0, 2.0].to_vec(), [3.0].to_vec()];
+
+    assert_eq!(
+        outliers_per_column(&matrix, 1.5),
+        Err(OutlierError::InconsistentRowLength {
+            row: 1,
+            expected: 2,
+            found: 1
+        })
+    );
+}
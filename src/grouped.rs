@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{OutlierError, OutlierIdentifier};
+
+/// Performs the outlier identification independently within each group of a keyed data set.  Each
+/// group uses the `k_value` configured for its key in `config`, or `default_k` if its key has no
+/// entry, which allows noisier groups to be given a larger `k_value`.  The returned map holds, for
+/// every group, the same partitioned tuple that `get_outliers()` returns.  An `Err` is returned if
+/// any group contains one or more `NAN`s or is assigned a negative `k_value`.
+#[allow(clippy::type_complexity)]
+pub fn grouped_outliers_with_config<K: Eq + Hash>(
+    data: Vec<(K, f64)>,
+    config: HashMap<K, f64>,
+    default_k: f64,
+) -> Result<HashMap<K, (Vec<f64>, Vec<f64>, Vec<f64>)>, OutlierError> {
+    let mut groups: HashMap<K, Vec<f64>> = HashMap::new();
+
+    for (key, value) in data {
+        groups.entry(key).or_default().push(value);
+    }
+
+    let mut results = HashMap::with_capacity(groups.len());
+
+    for (key, data_set) in groups {
+        let k_value = config.get(&key).copied().unwrap_or(default_k);
+        let results_tuple = OutlierIdentifier::new(data_set, false)
+            .with_k_value(k_value)
+            .get_outliers()?;
+
+        results.insert(key, results_tuple);
+    }
+
+    Ok(results)
+}
+
+#[test]
+fn grouped_outliers_with_config_per_group_k_value() {
+    let values = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ];
+    let data = values
+        .iter()
+        .map(|&value| ("quiet", value))
+        .chain(values.iter().map(|&value| ("noisy", value)))
+        .collect();
+    let config = [("noisy", 3.0)].into_iter().collect();
+    let results = grouped_outliers_with_config(data, config, 1.5).unwrap();
+
+    assert_eq!(results["quiet"].2, [22.0].to_vec());
+    assert_eq!(results["noisy"].2, [].to_vec());
+    assert_eq!(results["noisy"].1.len(), values.len());
+}
+
+#[test]
+fn grouped_outliers_with_config_negative_k_value_error() {
+    let data = [("a", 1.0), ("b", 2.0)].to_vec();
+    let config = [("b", -1.0)].into_iter().collect();
+    let results = grouped_outliers_with_config(data, config, 1.5);
+
+    assert!(matches!(results, Err(OutlierError::NegativeKValue)));
+}
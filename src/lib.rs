@@ -19,247 +19,6465 @@
 //! assert!(has_outliers);
 //! ```
 
-use statrs::statistics::OrderStatistics;
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::ops::Range;
+use std::sync::Arc;
+
+use num_traits::{NumCast, ToPrimitive};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use statrs::distribution::{Continuous, Normal, StudentsT, Univariate};
+use statrs::statistics::{Mean, Median, Variance};
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+use crate::rng::Rng;
+
+mod cleaning;
+mod columns;
+mod grouped;
+mod monitor;
+mod multivariate;
+mod rng;
+mod series;
+mod sorted;
+mod streaming;
+mod weighted;
+
+pub use cleaning::retain_non_outliers;
+pub use columns::{detect_all_columns, outliers_per_column};
+pub use grouped::grouped_outliers_with_config;
+pub use monitor::DriftMonitor;
+pub use multivariate::{magnitude_outliers, PairedOutlierIdentifier};
+pub use series::{
+    coalesce_outliers, local_outliers, log_return_outliers, monotonic_outliers, residual_outliers,
+    Direction,
+};
+pub use sorted::SortedData;
+pub use streaming::StreamingOutlierDetector;
+pub use weighted::WeightedOutlierIdentifier;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
 pub enum OutlierError {
     #[error("The data set contains one or more NANs")]
     ContainsNans,
+    #[error("The data set contains NANs at the indices {0:?}")]
+    ContainsNansAt(Vec<usize>),
+    #[error("The data set contains one or more infinities")]
+    ContainsInfinities,
     #[error("K value cannot be negative")]
     NegativeKValue,
+    #[error("The data set contains one or more non-positive values")]
+    ContainsNonPositiveValues,
+    #[error("Minimum retention must be between 0 and 1")]
+    InvalidRetentionFraction,
+    #[error("The assumed contamination must be at least 0 and less than 0.5")]
+    InvalidContaminationFraction,
+    #[error("No k value can retain the minimum fraction of the data set")]
+    UnattainableRetention,
+    #[error("Percentiles must be between 0 and 100, with the lower less than the upper")]
+    InvalidPercentiles,
+    #[error("The degrees of freedom must be positive")]
+    InvalidDegreesOfFreedom,
+    #[error("The tail probability must be between 0 and 0.5")]
+    InvalidTailProbability,
+    #[error("The significance level must be between 0 and 1")]
+    InvalidSignificanceLevel,
+    #[error("At least {required} values are required, but the data set has {found}")]
+    InsufficientData { required: usize, found: usize },
+    #[error("The covariance matrix is singular")]
+    SingularCovariance,
+    #[error("At most {maximum} values are supported, but the data set has {found}")]
+    ExcessiveData { maximum: usize, found: usize },
+    #[error("The lower fence cannot be greater than the upper fence, and neither can be NAN")]
+    InvalidFences,
+    #[error("The minimum of the clamp cannot be greater than the maximum, and neither can be NAN")]
+    InvalidClampBounds,
+    #[error("Weights cannot be negative or NAN")]
+    InvalidWeight,
+    #[error("Index {index} is out of range for a data set of {len} values")]
+    IndexOutOfRange { index: usize, len: usize },
+    #[error("The period must be at least 2")]
+    InvalidPeriod,
+    #[error("The window must be at least 1")]
+    InvalidWindow,
+    #[error("The neighborhood size must be at least 2")]
+    InvalidNeighborhoodSize,
+    #[error("The number of folds must be at least 2 and at most the size of the data set")]
+    InvalidFoldCount,
+    #[error("The number of mixture components must be at least 1")]
+    InvalidComponentCount,
+    #[error("The data set is empty")]
+    EmptyDataSet,
+    #[error("Failed to read the data set: {0}")]
+    ReadError(String),
+    #[error("Failed to write the results: {0}")]
+    WriteError(String),
+    #[error("{values} values were given, but {errors} measurement errors")]
+    MismatchedMeasurementErrors { values: usize, errors: usize },
+    #[error("Measurement errors cannot be negative or NAN")]
+    InvalidMeasurementError,
+    #[error("Row {row} has {found} fields, but {expected} were expected")]
+    InconsistentRowLength {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("{label}: {error}")]
+    Labeled {
+        label: String,
+        error: Box<OutlierError>,
+    },
 }
 
-pub struct OutlierIdentifier {
-    data_set: Vec<f64>,
+// With the `serde` feature, the configuration and data set can be serialized, but the predicate
+// set by `with_exclude()` and the strategy set by `with_strategy()` can't be, so they are skipped,
+// along with the values set aside by options
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutlierIdentifier<T = f64> {
+    data_set: Vec<T>,
+    measurement_errors: Option<Vec<f64>>,
+    original_indices: Option<Vec<usize>>,
     k_value: f64,
+    upper_k_value: Option<f64>,
     data_is_sorted: bool,
+    skip_nans: bool,
+    preserve_order: bool,
+    infinities_as_outliers: bool,
+    log_transform: bool,
+    inclusive_fences: bool,
+    label: Option<String>,
+    min_retention: Option<f64>,
+    min_size: Option<usize>,
+    error_on_empty: bool,
+    assumed_contamination: Option<f64>,
+    stuck_value_min_run: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    stuck_values: Vec<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    exclude: Option<Arc<dyn Fn(f64) -> bool + Send + Sync>>,
+    excluded_indices: Option<Vec<usize>>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    excluded_values: Vec<T>,
+    clamp: Option<(f64, f64)>,
+    detection_method: DetectionMethod,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    strategy: Option<Arc<dyn OutlierStrategy + Send + Sync>>,
+    quartile_method: QuartileMethod,
+    min_gap: Option<f64>,
+    fixed_fences: Option<Fences>,
+    output_precision: Option<u32>,
 }
 
-impl OutlierIdentifier {
+/// A bound on the type of the data set, which, with the `rayon` feature, requires it to be
+/// `Send + Sync` so that the data set can be sorted and partitioned in parallel.  Without the
+/// feature, every type implements it.
+#[cfg(feature = "rayon")]
+pub trait MaybeParallel: Send + Sync {}
+
+#[cfg(feature = "rayon")]
+impl<T: Send + Sync> MaybeParallel for T {}
+
+/// A bound on the type of the data set, which, with the `rayon` feature, requires it to be
+/// `Send + Sync` so that the data set can be sorted and partitioned in parallel.  Without the
+/// feature, every type implements it.
+#[cfg(not(feature = "rayon"))]
+pub trait MaybeParallel {}
+
+#[cfg(not(feature = "rayon"))]
+impl<T> MaybeParallel for T {}
+
+/// The data set partitioned into its lower outliers, non-outliers, and upper outliers.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OutlierResult<T = f64> {
+    pub lower: Vec<T>,
+    pub non_outliers: Vec<T>,
+    pub upper: Vec<T>,
+}
+
+impl<T: Clone> OutlierResult<T> {
+    /// The lower outliers.
+    pub fn lower(&self) -> &[T] {
+        &self.lower
+    }
+
+    /// The non-outliers.
+    pub fn non_outliers(&self) -> &[T] {
+        &self.non_outliers
+    }
+
+    /// The upper outliers.
+    pub fn upper(&self) -> &[T] {
+        &self.upper
+    }
+
+    /// Returns the lower outliers followed by the upper outliers.
+    pub fn all_outliers(&self) -> Vec<T> {
+        self.lower.iter().chain(&self.upper).cloned().collect()
+    }
+
+    /// Returns the number of lower and upper outliers.
+    pub fn outlier_count(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+}
+
+impl<T> From<(Vec<T>, Vec<T>, Vec<T>)> for OutlierResult<T> {
+    fn from(results_tuple: (Vec<T>, Vec<T>, Vec<T>)) -> OutlierResult<T> {
+        let (lower, non_outliers, upper) = results_tuple;
+
+        OutlierResult {
+            lower,
+            non_outliers,
+            upper,
+        }
+    }
+}
+
+/// The data set partitioned into its outliers and four quartile-relative groups of non-outliers, as
+/// returned by `partition_detailed()`.  Each group is in ascending order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedPartition {
+    /// The lower outliers.
+    pub lower: Vec<f64>,
+    /// The non-outliers below the lower quartile.
+    pub below_lower_quartile: Vec<f64>,
+    /// The non-outliers from the lower quartile, inclusive, to the median, exclusive.
+    pub lower_quartile_to_median: Vec<f64>,
+    /// The non-outliers from the median to the upper quartile, both inclusive.
+    pub median_to_upper_quartile: Vec<f64>,
+    /// The non-outliers above the upper quartile.
+    pub above_upper_quartile: Vec<f64>,
+    /// The upper outliers.
+    pub upper: Vec<f64>,
+}
+
+/// The data set partitioned by Tukey's inner and outer fences into mild and extreme outliers, as
+/// returned by `get_outliers_tiered()`.  Each group is in ascending order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TieredPartition {
+    /// The lower outliers below the outer lower fence.
+    pub extreme_lower: Vec<f64>,
+    /// The lower outliers between the outer and inner lower fences.
+    pub mild_lower: Vec<f64>,
+    /// The values within the inner fences.
+    pub non_outliers: Vec<f64>,
+    /// The upper outliers between the inner and outer upper fences.
+    pub mild_upper: Vec<f64>,
+    /// The upper outliers above the outer upper fence.
+    pub extreme_upper: Vec<f64>,
+}
+
+/// The lower and upper fences; values below `lower` or above `upper` are outliers.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fences {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl Fences {
+    /// Partitions `data` into its lower outliers, non-outliers, and upper outliers against these
+    /// fences, such as for monitoring each new batch against fences learned from a known-good
+    /// baseline with `learn_fences()`, without sorting or computing quartiles again.  Each
+    /// partition keeps the order of `data`.  The comparisons are strict, and a `NAN` is never
+    /// beyond a fence, so it is returned as a non-outlier.
+    #[allow(clippy::type_complexity)]
+    pub fn classify_batch(&self, data: &[f64]) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+        let mut partitions = (Vec::new(), Vec::new(), Vec::new());
+
+        for &value in data {
+            match Classification::new(value, self.lower, self.upper) {
+                Classification::LowerOutlier => partitions.0.push(value),
+                Classification::NonOutlier => partitions.1.push(value),
+                Classification::UpperOutlier => partitions.2.push(value),
+            }
+        }
+
+        partitions
+    }
+}
+
+/// The quartiles, fences, and extremes of a data set, as returned by `summary()`, such as for
+/// drawing a box plot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Summary {
+    /// The lower quartile.
+    pub q1: f64,
+    pub median: f64,
+    /// The upper quartile.
+    pub q3: f64,
+    /// The interquartile range, `q3 - q1`.
+    pub iqr: f64,
+    pub lower_fence: f64,
+    pub upper_fence: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+/// The classification of a single value relative to the fences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Classification {
+    /// The value is below the lower fence.
+    LowerOutlier,
+    /// The value is within the fences.
+    NonOutlier,
+    /// The value is above the upper fence.
+    UpperOutlier,
+}
+
+impl Classification {
+    pub(crate) fn new(value: f64, lower_fence: f64, upper_fence: f64) -> Classification {
+        if value < lower_fence {
+            Classification::LowerOutlier
+        } else if value > upper_fence {
+            Classification::UpperOutlier
+        } else {
+            Classification::NonOutlier
+        }
+    }
+
+    fn from_side(side: Ordering) -> Classification {
+        match side {
+            Ordering::Less => Classification::LowerOutlier,
+            Ordering::Equal => Classification::NonOutlier,
+            Ordering::Greater => Classification::UpperOutlier,
+        }
+    }
+}
+
+/// The format of the records written by `write_outliers()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Comma-separated values, with a `value,side` header, e.g. `22,upper`.
+    Csv,
+    /// One JSON object per line, e.g. `{"value":22,"side":"upper"}`.
+    Jsonl,
+}
+
+/// An assessment of how plausible a `k_value` is, as returned by `check_k()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KAssessment {
+    /// The `k_value` is within the range commonly used in practice.
+    Reasonable,
+    /// The `k_value` is so small that a large share of most data sets will be flagged.
+    VeryAggressive,
+    /// The `k_value` is so large that almost nothing will ever be flagged.
+    VeryLenient,
+}
+
+/// A single step of `iterative_removal_trace()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RemovalStep {
+    /// The outlier removed in this step.
+    pub removed_value: f64,
+    /// The number of values remaining after the removal.
+    pub remaining_count: usize,
+    /// The fences recomputed from the remaining values.
+    pub fences: Fences,
+}
+
+/// The mean, median, and standard deviation of a set of values.  Any statistic that is undefined
+/// for the set, such as the standard deviation of fewer than two values, is `NAN`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DescriptiveStatistics {
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+}
+
+impl DescriptiveStatistics {
+    fn from_data(data: &[f64]) -> DescriptiveStatistics {
+        DescriptiveStatistics {
+            mean: Mean::mean(data),
+            median: Median::median(data),
+            std_dev: Variance::std_dev(data),
+        }
+    }
+}
+
+/// The statistics of the full data set, alongside the statistics of only its non-outliers, as
+/// returned by `impact_summary()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ImpactSummary {
+    pub raw: DescriptiveStatistics,
+    pub cleaned: DescriptiveStatistics,
+}
+
+impl<T: NumCast + PartialOrd + Clone + MaybeParallel> OutlierIdentifier<T> {
     /// Creates a new `OutlierIdentifier`.  The default `k_value` is `1.5`, a value in outlier
     /// identification made popular by the mathematician John Tukey.  If the order state of the data
-    /// is unknown, then use `false` for `data_is_sorted`.
-    pub fn new(data_set: Vec<f64>, data_is_sorted: bool) -> OutlierIdentifier {
+    /// is unknown, then use `false` for `data_is_sorted`; in debug builds, passing `true` with
+    /// unsorted data panics, while release builds trust it and skip the check.  The data set can
+    /// hold any primitive numeric type, such as `i32`, `u64`, or `f32`; the fences are computed as
+    /// `f64`s, but the partitions returned by `get_outliers()` hold the original values.  An `f32`
+    /// data set is never copied into an `f64` buffer; each value is only widened as it is
+    /// compared, so the memory use stays that of the `f32`s, and the quartiles and fences don't pick
+    /// up the rounding error of `f32` arithmetic.  The methods that return other results, such as
+    /// `classify_flat()` or `metrics()`, require a data set of `f64`s.
+    pub fn new(data_set: Vec<T>, data_is_sorted: bool) -> OutlierIdentifier<T> {
         OutlierIdentifier {
             data_set,
+            measurement_errors: None,
+            original_indices: None,
             data_is_sorted,
             k_value: 1.5,
+            upper_k_value: None,
+            skip_nans: false,
+            preserve_order: false,
+            infinities_as_outliers: false,
+            log_transform: false,
+            inclusive_fences: false,
+            label: None,
+            min_retention: None,
+            min_size: None,
+            error_on_empty: false,
+            assumed_contamination: None,
+            stuck_value_min_run: None,
+            stuck_values: Vec::new(),
+            exclude: None,
+            excluded_indices: None,
+            clamp: None,
+            excluded_values: Vec::new(),
+            detection_method: DetectionMethod::Tukey,
+            strategy: None,
+            quartile_method: QuartileMethod::MedianUnbiased,
+            min_gap: None,
+            fixed_fences: None,
+            output_precision: None,
+        }
+    }
+
+    /// Creates a new `OutlierIdentifier` from a slice, such as an array or a borrowed `Vec`, which
+    /// is copied, so the caller keeps ownership of its data.  Otherwise, it is identical to `new()`.
+    pub fn from_slice(data_set: &[T], data_is_sorted: bool) -> OutlierIdentifier<T> {
+        OutlierIdentifier::new(data_set.to_vec(), data_is_sorted)
+    }
+
+    /// Creates a new `OutlierIdentifier` for measurements that each come with an uncertainty, such as
+    /// a standard error, with a `k_value` of `k_value`.  The fences are computed from `values` as
+    /// usual, but a value is only an outlier if it remains beyond a fence even when moved by its
+    /// error towards the fences: a value is an upper outlier if `value - error` is above the upper
+    /// fence, and a lower outlier if `value + error` is below the lower fence.  This keeps an
+    /// imprecise measurement that is consistent with the bulk of the data set, within its error
+    /// bars, from being flagged.  The errors apply to `get_outliers()` and the methods built upon
+    /// it.  `get_outliers()` will return an `Err` if `values` and `errors` have different lengths,
+    /// or if any of the errors is negative or `NAN`.
+    pub fn new_with_errors(values: Vec<T>, errors: Vec<f64>, k_value: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            measurement_errors: Some(errors),
+            original_indices: Some((0..values.len()).collect()),
+            ..OutlierIdentifier::new(values, false).with_k_value(k_value)
+        }
+    }
+
+    /// Appends the data set of `other` to this `OutlierIdentifier`'s data set, such as for
+    /// identifying the outliers across the measurements of two sensors.  The order of the combined
+    /// data set is unknown, so it is treated as unsorted.  Every option, including the `k_value`,
+    /// is kept from `self`, and the options of `other` are discarded.  If either data set was
+    /// created with `new_with_errors()`, the measurement errors are combined as well, with an error
+    /// of `0.0` for each value of the other data set.
+    pub fn merge(self, other: OutlierIdentifier<T>) -> OutlierIdentifier<T> {
+        let (len, other_len) = (self.data_set.len(), other.data_set.len());
+        let measurement_errors = match (self.measurement_errors, other.measurement_errors) {
+            (None, None) => None,
+            (measurement_errors, other_measurement_errors) => Some(
+                [
+                    measurement_errors.unwrap_or_else(|| vec![0.0; len]),
+                    other_measurement_errors.unwrap_or_else(|| vec![0.0; other_len]),
+                ]
+                .concat(),
+            ),
+        };
+        let original_indices = measurement_errors
+            .as_ref()
+            .map(|_| (0..len + other_len).collect());
+        let mut data_set = self.data_set;
+        data_set.extend(other.data_set);
+
+        OutlierIdentifier {
+            data_set,
+            measurement_errors,
+            original_indices,
+            data_is_sorted: false,
+            ..self
+        }
+    }
+
+    /// Allows for altering the `k_value`.  A larger `k_value` will result in fewer numbers being
+    /// identified as outliers, while a smaller `k_value` will result in more numbers being
+    /// identified as outliers.  The `k_value` must be non-negative, or `get_outliers()` will return
+    /// an `Err`.
+    pub fn with_k_value(self, k_value: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            k_value,
+            upper_k_value: None,
+            ..self
+        }
+    }
+
+    /// Allows for separate `k_value`s for each fence, such as a tighter fence on the short side of
+    /// a skewed distribution: the fences are `lower_quartile - lower_k_value * IQR` and
+    /// `upper_quartile + upper_k_value * IQR`.  Both `k_value`s must be non-negative, or
+    /// `get_outliers()` will return an `Err`.  Like the `k_value`, they only apply to
+    /// `DetectionMethod::Tukey`.  Calling `with_k_value()` afterwards sets both fences back to a
+    /// single `k_value`.
+    pub fn with_k_values(self, lower_k_value: f64, upper_k_value: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            k_value: lower_k_value,
+            upper_k_value: Some(upper_k_value),
+            ..self
+        }
+    }
+
+    /// Allows for altering the method used to compute the fences.  The default is
+    /// `DetectionMethod::Tukey`.
+    pub fn with_detection_method(self, detection_method: DetectionMethod) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            detection_method,
+            ..self
+        }
+    }
+
+    /// Computes the fences with a custom `OutlierStrategy`, rather than with the detection method,
+    /// so that a method this crate doesn't provide can be used without forking it.  The strategy is
+    /// given the data set after the options that remove values from it, such as `with_skip_nans()`,
+    /// and the infinities and any assumed contamination are applied, sorted, and, with
+    /// `with_log_transform()`, on the log scale.  A data set of fewer than two values never reaches
+    /// the strategy, as its fences are always infinite.  The `k_value`, the detection method, and
+    /// `with_min_retention()` are ignored, but `with_min_gap()` still adjusts the fences.  The
+    /// strategy applies to `get_outliers()` and the methods that classify with the same fences,
+    /// such as `fences()`.  `get_outliers()` will return any `Err` that the strategy returns, or
+    /// an `Err` if the lower fence it returns is greater than the upper fence or if either is
+    /// `NAN`.
+    pub fn with_strategy(
+        self,
+        strategy: impl OutlierStrategy + Send + Sync + 'static,
+    ) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            strategy: Some(Arc::new(strategy)),
+            ..self
+        }
+    }
+
+    /// Allows for altering how the quartiles that `DetectionMethod::Tukey` places its fences around
+    /// are computed, such as to match the quartiles of another tool exactly.  The default is
+    /// `QuartileMethod::MedianUnbiased`.  The other detection methods aren't affected.
+    pub fn with_quartile_method(self, quartile_method: QuartileMethod) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            quartile_method,
+            ..self
+        }
+    }
+
+    /// Sets the `k_value` so that, for normally distributed data, the fences lie `n_sigma` standard
+    /// deviations from the median.  For a normal distribution, the upper quartile is `0.6745`
+    /// standard deviations above the median and the interquartile range is `1.349` standard
+    /// deviations, so the `k_value` used is `n_sigma / 1.349 - 0.5`.  For example, `n_sigma` of
+    /// `2.698` corresponds to the default `k_value` of `1.5`.  The fences are still computed from
+    /// the quartiles, so they remain robust to outliers.  An `n_sigma` below `0.6745` produces a
+    /// negative `k_value`, which will cause `get_outliers()` to return an `Err`.
+    pub fn with_sigma_k(self, n_sigma: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            k_value: n_sigma / NORMAL_IQR_PER_SIGMA - 0.5,
+            upper_k_value: None,
+            ..self
+        }
+    }
+
+    /// Guarantees that the fences retain at least the `min_retention` fraction of the data set as
+    /// non-outliers.  If the fences produced by the `k_value` would retain less than that, the
+    /// `k_value` is widened to the smallest value that meets the constraint; the `k_value` actually
+    /// used is reported by `effective_k_value()`.  This is useful when later steps break if too much
+    /// data is dropped.  `min_retention` must be between `0.0` and `1.0`, and the constraint can't
+    /// be met if the interquartile range is zero while too many values lie outside of it; in either
+    /// case, `get_outliers()` will return an `Err`.
+    pub fn with_min_retention(self, min_retention: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            min_retention: Some(min_retention),
+            ..self
+        }
+    }
+
+    /// Requires the data set to hold at least `min_size` values before the fences are computed, so
+    /// that a pipeline fails loudly rather than partitioning a sample too small for its quartiles to
+    /// be meaningful.  The size is counted after the options that remove values from the data set,
+    /// such as `with_skip_nans()`, are applied.  By default, there is no minimum.  If the data set
+    /// is smaller than `min_size`, `get_outliers()` will return an
+    /// `OutlierError::InsufficientData`.
+    pub fn with_min_size(self, min_size: usize) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            min_size: Some(min_size),
+            ..self
+        }
+    }
+
+    /// Makes `get_outliers()` return an `OutlierError::EmptyDataSet` when `error_on_empty` is
+    /// `true` and the data set passed in is empty, so that a pipeline that should never receive an
+    /// empty data set fails loudly rather than silently returning empty partitions.  By default,
+    /// an empty data set has no outliers, and all three partitions are empty.  A data set that
+    /// only becomes empty after options such as `with_skip_nans()` are applied isn't rejected; use
+    /// `with_min_size()` for that.
+    pub fn with_error_on_empty(self, error_on_empty: bool) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            error_on_empty,
+            ..self
+        }
+    }
+
+    /// Computes the fences from only the most central `1 - fraction` of the data set, the values
+    /// closest to the median, and then classifies every value against those fences.  This gives the
+    /// fences a breakdown point of `fraction`: up to that fraction of the data set can be
+    /// arbitrarily corrupted without moving the fences, much like the minimum covariance determinant
+    /// estimator.  Since the trimmed values are excluded from the spread even when they are
+    /// legitimate, the fences are tighter than those computed from the full data set, so a larger
+    /// `k_value` may be appropriate.  `fraction` must be at least `0.0` and less than `0.5`, or
+    /// `get_outliers()` will return an `Err`.
+    pub fn with_assumed_contamination(self, fraction: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            assumed_contamination: Some(fraction),
+            ..self
+        }
+    }
+
+    /// Requires a clear break between the outliers and the non-outliers.  A value beyond a fence is
+    /// only confirmed as an outlier if the gap between it and the nearest non-outlier exceeds
+    /// `min_gap`; otherwise, it is reclassified as a non-outlier, and the next value beyond it is
+    /// then compared against it.  This reduces false positives in gradual tails, where the fences
+    /// would otherwise split a continuous run of values.
+    pub fn with_min_gap(self, min_gap: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            min_gap: Some(min_gap),
+            ..self
+        }
+    }
+
+    /// Classifies the data set against fixed fences, such as those learned from a known-good
+    /// baseline with `fences()`, rather than computing the fences from the data set itself, so that
+    /// new batches can be monitored against the baseline.  The options that remove values from the
+    /// data set, such as `with_skip_nans()`, still apply, but the `k_value`, the detection method,
+    /// and the options that adjust the fences, such as `with_min_gap()`, are ignored.
+    /// `get_outliers()` will return an `Err` if `lower_fence` is greater than `upper_fence` or if
+    /// either is `NAN`.
+    pub fn with_fixed_fences(self, lower_fence: f64, upper_fence: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            fixed_fences: Some(Fences {
+                lower: lower_fence,
+                upper: upper_fence,
+            }),
+            ..self
+        }
+    }
+
+    /// Enables the detection of stuck values, such as those produced by a frozen sensor that keeps
+    /// repeating its last reading.  Every run of `min_run` or more identical, consecutive values is
+    /// removed before the fences are computed, so the runs can't distort the quartiles, and the
+    /// removed values don't appear in any of the partitions returned by `get_outliers()`.  Use
+    /// `get_outliers_with_stuck_values()` to retrieve them.  Runs are detected in the order that
+    /// the data set was passed in; note that if `data_is_sorted` is `true`, any value that occurs
+    /// `min_run` or more times forms a run.  `min_run` should be at least `2`.
+    pub fn with_stuck_value_detection(self, min_run: usize) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            stuck_value_min_run: Some(min_run),
+            ..self
+        }
+    }
+
+    /// Allows for skipping `NAN`s, such as gaps in sensor data, rather than returning an `Err`.  When
+    /// `skip_nans` is `true`, every `NAN` is removed before the fences are computed, and the `NAN`s
+    /// don't appear in any of the partitions returned by `get_outliers()`, including the
+    /// non-outliers, so the partitions no longer hold the entire data set.  By default, `NAN`s are
+    /// not skipped.
+    pub fn with_skip_nans(self, skip_nans: bool) -> OutlierIdentifier<T> {
+        OutlierIdentifier { skip_nans, ..self }
+    }
+
+    /// Allows for computing the fences on the natural logarithm of the data set, which suits
+    /// right-skewed data, such as incomes or file sizes, whose logarithm is roughly normally
+    /// distributed.  When `log_transform` is `true`, the fences are computed on the log scale and
+    /// then mapped back, so the fences, as returned by `fences()`, and the partitions are on the
+    /// original scale, and the fences are no longer symmetric about the quartiles.  The options
+    /// that adjust the fences, other than `with_min_gap()`, whose gap is on the original scale,
+    /// apply on the log scale.  `get_outliers()` will return an `Err` if the log transform is
+    /// enabled and the data set contains one or more non-positive values.  By default, the data set
+    /// isn't transformed.
+    pub fn with_log_transform(self, log_transform: bool) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            log_transform,
+            ..self
+        }
+    }
+
+    /// Allows for returning the partitions in the order that the data set was passed in, such as to
+    /// keep the non-outliers lined up with their timestamps, rather than in ascending order.  The
+    /// fences are computed from the sorted data set as usual, and, when `preserve_order` is `true`,
+    /// the values are then partitioned in their original order.  The indices returned by
+    /// `get_outlier_indices()` are then in ascending order as well.  By default, the order isn't
+    /// preserved.
+    pub fn with_preserve_order(self, preserve_order: bool) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            preserve_order,
+            ..self
+        }
+    }
+
+    /// Controls whether a value exactly on a fence is an outlier.  By default, the comparisons are
+    /// strict, so a value equal to a fence is a non-outlier; when `inclusive_fences` is `true`, the
+    /// values less than or equal to the lower fence and greater than or equal to the upper fence
+    /// are the outliers, as some definitions of Tukey's method have it.  This only matters for data
+    /// sets with values landing exactly on a fence, such as integer data.  If the fences coincide,
+    /// such as for a data set whose values are all identical, they are exclusive regardless, so
+    /// that the values on them remain non-outliers.
+    pub fn with_inclusive_fences(self, inclusive_fences: bool) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            inclusive_fences,
+            ..self
+        }
+    }
+
+    /// Allows for data sets containing infinities, such as a sensor reading that overflowed, rather
+    /// than returning an `Err`.  When `infinities_as_outliers` is `true`, positive infinities are
+    /// always upper outliers and negative infinities are always lower outliers, and the fences are
+    /// computed from only the finite values, so the infinities can't distort the quartiles.  By
+    /// default, infinities are not allowed.
+    pub fn with_infinities_as_outliers(self, infinities_as_outliers: bool) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            infinities_as_outliers,
+            ..self
+        }
+    }
+
+    /// Excludes every value for which `predicate` returns `true`, such as a sentinel value like
+    /// `-999.0` that marks a missing reading, from the outlier identification.  The excluded values
+    /// are removed before the fences are computed, so they can't distort the quartiles, and they
+    /// don't appear in any of the partitions returned by `get_outliers()`.  Use
+    /// `get_outliers_with_excluded()` to retrieve them.  `predicate` is called once for each value
+    /// of the data set, before any stuck values are detected.
+    pub fn with_exclude(
+        self,
+        predicate: impl Fn(f64) -> bool + Send + Sync + 'static,
+    ) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            exclude: Some(Arc::new(predicate)),
+            ..self
+        }
+    }
+
+    /// Excludes the values at `indices` of the data set, as it was passed in, such as samples known
+    /// to be instrument errors, from the outlier identification.  As with `with_exclude()`, the
+    /// excluded values are removed before the fences are computed, so they can't widen the IQR
+    /// enough to hide genuine outliers, and `get_outliers_with_excluded()` returns them, ahead of
+    /// any values excluded by `with_exclude()`.  Repeated indices are only excluded once.
+    /// `get_outliers()` will return an `Err` if any of the `indices` is out of range.
+    pub fn with_excluded_indices(self, indices: &[usize]) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            excluded_indices: Some(indices.to_vec()),
+            ..self
+        }
+    }
+
+    /// Clamps every value of the data set into the range from `min` to `max` before the fences are
+    /// computed, such as for pulling physically impossible readings, like negative temperatures in
+    /// Kelvin, back to the limits of the domain, so that they don't distort the quartiles.  Unlike
+    /// fences derived from the data, the bounds are taken as given, and the clamped values are
+    /// partitioned, and returned, at their clamped value.  The values are clamped after those
+    /// removed by the other options, such as `with_skip_nans()`, so `NAN`s are never clamped.  If
+    /// `min` is greater than `max`, or either is `NAN`, `get_outliers()` will return an `Err`.
+    pub fn with_clamp(self, min: f64, max: f64) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            clamp: Some((min, max)),
+            ..self
+        }
+    }
+
+    /// Rounds every returned value, including the fences, outliers, and non-outliers, to `decimals`
+    /// decimal places, so that reports show `73.0` rather than `73.00000000001`.  The rounding is
+    /// only applied to the output; the fences are computed and the values are classified at full
+    /// precision.  By default, no rounding is performed.
+    pub fn with_output_precision(self, decimals: u32) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            output_precision: Some(decimals),
+            ..self
+        }
+    }
+
+    /// Tags the `OutlierIdentifier` with a label, such as the name of the data set or sensor.  Any
+    /// `Err` produced afterwards is wrapped in `OutlierError::Labeled`, so that its message is
+    /// prefixed with the label, e.g. "sensor_42: The data set contains one or more NANs".  By
+    /// default, no label is set and errors are returned unwrapped.
+    pub fn with_label(self, label: &str) -> OutlierIdentifier<T> {
+        OutlierIdentifier {
+            label: Some(label.to_string()),
+            ..self
+        }
+    }
+
+    /// Assesses whether the configured `k_value` is likely to be a mistake, so that callers can
+    /// surface a warning.  A `k_value` below `0.5` is `VeryAggressive`, since fences that close to
+    /// the quartiles flag a large share of even normally distributed data, and a `k_value` above
+    /// `5.0` is `VeryLenient`, since such fences are wider than Tukey's `3.0` for "far out" values
+    /// by a wide margin; anything in between is `Reasonable`.  If `with_k_values()` set a separate
+    /// `k_value` for each fence, both are assessed, and `VeryAggressive` takes precedence.  This
+    /// doesn't change the behavior of `get_outliers()`, which still only rejects a negative
+    /// `k_value`.
+    pub fn check_k(&self) -> KAssessment {
+        const MIN_REASONABLE_K_VALUE: f64 = 0.5;
+        const MAX_REASONABLE_K_VALUE: f64 = 5.0;
+
+        let upper_k_value = self.upper_k_value();
+
+        if self.k_value.min(upper_k_value) < MIN_REASONABLE_K_VALUE {
+            KAssessment::VeryAggressive
+        } else if self.k_value.max(upper_k_value) > MAX_REASONABLE_K_VALUE {
+            KAssessment::VeryLenient
+        } else {
+            KAssessment::Reasonable
+        }
+    }
+
+    /// Suggests a `k_value` for the size of the data set; it isn't applied, so it must be passed to
+    /// `with_k_value()`.  The suggestion is the `k_value` at which a normally distributed data set
+    /// of the same size is expected to have only `0.5` values beyond the fences, from `1.58` for
+    /// `100` values to `2.08` for `1,000`, but never less than Tukey's `1.5`.  `suggested_k()` will
+    /// return an `Err` if the data set is empty.
+    pub fn suggested_k(&self) -> Result<f64, OutlierError> {
+        const EXPECTED_OUTLIER_COUNT: f64 = 0.5;
+        const MIN_SUGGESTED_K_VALUE: f64 = 1.5;
+
+        if self.data_set.is_empty() {
+            return Err(self.label_error(OutlierError::EmptyDataSet));
+        }
+
+        // For the standard normal distribution, the upper quartile is about `0.6745`, and the IQR is
+        // twice that, so the upper fence lies at `upper_quartile + k_value * 2 * upper_quartile`
+        let standard_normal = Normal::new(0.0, 1.0).unwrap();
+        let upper_quartile = inverse_cdf(&standard_normal, 0.75);
+        let tail_probability = EXPECTED_OUTLIER_COUNT / (2.0 * self.data_set.len() as f64);
+        let upper_fence = inverse_cdf(&standard_normal, 1.0 - tail_probability);
+        let k_value = (upper_fence - upper_quartile) / (2.0 * upper_quartile);
+
+        Ok(k_value.max(MIN_SUGGESTED_K_VALUE))
+    }
+
+    /// Performs the outlier identification.  In the case that is does not return an `Err`, it
+    /// returns a tuple of `Vec<T>`s.  The first vector contains any lower outliers and the third
+    /// vector contains any upper outliers.  Additionally, the second vector returned contains all
+    /// the non-outliers, so that the data set passed in is returned, in its entirety, as
+    /// partitioned subsets.  A data set of fewer than two values has no spread to judge its values
+    /// by, so its value, if any, is always a non-outlier, unless `with_fixed_fences()` is used, and
+    /// if every value is identical, both fences lie on that value.  `get_outliers()` will return an
+    /// `Err` if the `data_set` contains one or more `NAN`s or infinities, unless allowed by
+    /// `with_skip_nans()` or `with_infinities_as_outliers()`, if the `k_value` is a negative
+    /// number, or if an option's requirement isn't met, such as the size set by `with_min_size()`.
+    /// Every method that computes the fences returns an `Err` under these same conditions, in
+    /// addition to any of its own.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers(mut self) -> Result<(Vec<T>, Vec<T>, Vec<T>), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        Ok(self.partition(lower_fence, upper_fence))
+    }
+
+    /// Returns the lower and upper fences that [`get_outliers()`](Self::get_outliers) classifies
+    /// the data set with, such as for labeling a chart, rounded if `with_output_precision()` was
+    /// used.  The data set is sorted in a copy, so the `OutlierIdentifier` remains usable.
+    pub fn fences(&self) -> Result<(f64, f64), OutlierError> {
+        let (lower_fence, upper_fence) = self.clone().get_fences()?;
+
+        Ok((
+            round_to_precision(lower_fence, self.output_precision),
+            round_to_precision(upper_fence, self.output_precision),
+        ))
+    }
+
+    /// Returns the fences, as `fences()` does, but as `Fences`, which can then classify later
+    /// batches of data with `classify_batch()`, such as for learning the fences from training data
+    /// once.
+    pub fn learn_fences(&self) -> Result<Fences, OutlierError> {
+        let (lower, upper) = self.fences()?;
+        Ok(Fences { lower, upper })
+    }
+
+    /// Returns the quartiles, median, and interquartile range of the data set that the fences of
+    /// [`get_outliers()`](Self::get_outliers) are computed from, alongside those fences and its
+    /// smallest and largest values, such as for debugging why a value was or wasn't flagged.  The
+    /// quartiles use the `QuartileMethod`, and, for an empty data set, every statistic is `NAN`.
+    pub fn summary(&self) -> Result<Summary, OutlierError> {
+        let mut outlier_identifier = self.clone();
+        let (lower_fence, upper_fence) = outlier_identifier.get_fences()?;
+        let sorted_data_set = &outlier_identifier.data_set;
+        let (q1, q3) = self.quartile_method.quartiles(sorted_data_set);
+        let round = |value| round_to_precision(value, self.output_precision);
+
+        Ok(Summary {
+            q1: round(q1),
+            median: round(quantile(sorted_data_set, 0.5)),
+            q3: round(q3),
+            iqr: round(q3 - q1),
+            lower_fence: round(lower_fence),
+            upper_fence: round(upper_fence),
+            min: round(sorted_data_set.first().map_or(f64::NAN, to_f64)),
+            max: round(sorted_data_set.last().map_or(f64::NAN, to_f64)),
+        })
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, and
+    /// additionally returns the fences that the data set was classified with, rounded as they are
+    /// by `fences()`, such as for archiving them alongside the partitions.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers_with_fences(
+        mut self,
+    ) -> Result<((Vec<T>, Vec<T>, Vec<T>), (f64, f64)), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let fences = (
+            round_to_precision(lower_fence, self.output_precision),
+            round_to_precision(upper_fence, self.output_precision),
+        );
+
+        Ok((self.partition(lower_fence, upper_fence), fences))
+    }
+
+    /// Classifies a single `value`, which needn't be part of the data set, against the fences of
+    /// [`get_outliers()`](Self::get_outliers), such as for checking new values against stable
+    /// reference data.  The fences are recomputed on every call, so use `fences()` once to classify
+    /// many values.
+    pub fn classify(&self, value: f64) -> Result<Classification, OutlierError> {
+        let (lower_fence, upper_fence) = self.clone().get_fences()?;
+        Ok(Classification::from_side(self.side(
+            value,
+            lower_fence,
+            upper_fence,
+        )))
+    }
+
+    /// Performs the outlier identification with `DetectionMethod::Percentile`, which flags the
+    /// values below the `lower_percentile` and above the `upper_percentile`, given from `0.0` to
+    /// `100.0`, such as the bottom and top 5% with `5.0` and `95.0`.  Besides the errors of
+    /// [`get_outliers()`](Self::get_outliers), `get_outliers_percentile()` will return an `Err` if
+    /// the percentiles are out of range or `lower_percentile` is greater than `upper_percentile`.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers_percentile(
+        self,
+        lower_percentile: f64,
+        upper_percentile: f64,
+    ) -> Result<(Vec<T>, Vec<T>, Vec<T>), OutlierError> {
+        self.with_detection_method(DetectionMethod::Percentile {
+            lower: lower_percentile,
+            upper: upper_percentile,
+        })
+        .get_outliers()
+    }
+
+    /// Performs the outlier identification with `DetectionMethod::ZScore`, which flags the values
+    /// more than `n_std` sample standard deviations from the mean, such as with `3.0` for
+    /// approximately normal data.  Besides the errors of [`get_outliers()`](Self::get_outliers),
+    /// `get_outliers_zscore()` will return an `Err` if `n_std` is negative.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers_zscore(self, n_std: f64) -> Result<(Vec<T>, Vec<T>, Vec<T>), OutlierError> {
+        self.with_detection_method(DetectionMethod::ZScore { n_std })
+            .get_outliers()
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// returns the partitions as an `OutlierResult`, whose named fields can't be mixed up the way
+    /// the elements of a tuple can.
+    pub fn get_outliers_struct(self) -> Result<OutlierResult<T>, OutlierError> {
+        self.get_outliers().map(OutlierResult::from)
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// borrows the `OutlierIdentifier` rather than consuming it, at the cost of one clone of the
+    /// data set.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers_ref(&self) -> Result<(Vec<T>, Vec<T>, Vec<T>), OutlierError> {
+        self.clone().get_outliers()
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// returns the indices of the values in the data set as it was passed in, such as for mapping
+    /// them back to timestamps.  Each vector of indices is ordered by value, and the indices of
+    /// values removed by an option are left out.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outlier_indices(
+        mut self,
+    ) -> Result<(Vec<usize>, Vec<usize>, Vec<usize>), OutlierError> {
+        if self.original_indices.is_none() {
+            self.original_indices = Some((0..self.data_set.len()).collect());
+        }
+
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let mut lower_outlier_indices = Vec::new();
+        let mut upper_outlier_indices = Vec::new();
+        let mut non_outlier_indices = Vec::new();
+
+        for (position, &original_index) in self.original_indices.iter().flatten().enumerate() {
+            match self.classify_position(position, lower_fence, upper_fence) {
+                Ordering::Less => lower_outlier_indices.push(original_index),
+                Ordering::Equal => non_outlier_indices.push(original_index),
+                Ordering::Greater => upper_outlier_indices.push(original_index),
+            }
+        }
+
+        if self.preserve_order {
+            lower_outlier_indices.sort_unstable();
+            non_outlier_indices.sort_unstable();
+            upper_outlier_indices.sort_unstable();
+        }
+
+        Ok((
+            lower_outlier_indices,
+            non_outlier_indices,
+            upper_outlier_indices,
+        ))
+    }
+
+    /// Returns a mask the same length as the data set as it was passed in, where `true` marks an
+    /// outlier, on either side, as classified by [`get_outliers()`](Self::get_outliers), such as
+    /// for filtering the parallel columns of a data frame.  The values removed by an option are
+    /// marked `false`.
+    pub fn outlier_mask(&self) -> Result<Vec<bool>, OutlierError> {
+        let mut outlier_mask = vec![false; self.data_set.len()];
+        let (lower_outlier_indices, _, upper_outlier_indices) =
+            self.clone().get_outlier_indices()?;
+
+        for index in lower_outlier_indices
+            .into_iter()
+            .chain(upper_outlier_indices)
+        {
+            outlier_mask[index] = true;
+        }
+
+        Ok(outlier_mask)
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, and
+    /// returns the non-outliers, followed by the outliers paired with their indices, both in the
+    /// order that the data set was passed in, such as for replacing the outliers while logging what
+    /// was dropped.  The values removed by an option appear in neither vector.
+    #[allow(clippy::type_complexity)]
+    pub fn split_outliers_ordered(&self) -> Result<(Vec<T>, Vec<(usize, T)>), OutlierError> {
+        // `None` marks the values that were removed before the outlier identification
+        let mut is_outlier = vec![None; self.data_set.len()];
+        let (lower_outlier_indices, non_outlier_indices, upper_outlier_indices) =
+            self.clone().get_outlier_indices()?;
+
+        for index in non_outlier_indices {
+            is_outlier[index] = Some(false);
+        }
+
+        for index in lower_outlier_indices
+            .into_iter()
+            .chain(upper_outlier_indices)
+        {
+            is_outlier[index] = Some(true);
+        }
+
+        let mut non_outliers = Vec::new();
+        let mut outliers = Vec::new();
+
+        for (index, (data, is_outlier)) in self.data_set.iter().zip(is_outlier).enumerate() {
+            let output_data = round_value(data.clone(), self.output_precision);
+
+            match is_outlier {
+                Some(false) => non_outliers.push(output_data),
+                Some(true) => outliers.push((index, output_data)),
+                None => {}
+            }
+        }
+
+        Ok((non_outliers, outliers))
+    }
+
+    /// Performs the outlier identification, as `get_outliers()` does, and additionally returns the
+    /// values removed by `with_stuck_value_detection()`, in their original order.  If stuck value
+    /// detection isn't enabled, the second element is always empty.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers_with_stuck_values(
+        mut self,
+    ) -> Result<((Vec<T>, Vec<T>, Vec<T>), Vec<T>), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let stuck_values = std::mem::take(&mut self.stuck_values)
+            .into_iter()
+            .map(|data| round_value(data, self.output_precision))
+            .collect();
+
+        Ok((self.partition(lower_fence, upper_fence), stuck_values))
+    }
+
+    /// Performs the outlier identification, as `get_outliers()` does, and additionally returns the
+    /// values removed by `with_excluded_indices()` and then those removed by `with_exclude()`, each
+    /// in their original order.  If neither option is set, the second element is always empty.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers_with_excluded(
+        mut self,
+    ) -> Result<((Vec<T>, Vec<T>, Vec<T>), Vec<T>), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let excluded_values = std::mem::take(&mut self.excluded_values)
+            .into_iter()
+            .map(|data| round_value(data, self.output_precision))
+            .collect();
+
+        Ok((self.partition(lower_fence, upper_fence), excluded_values))
+    }
+
+    /// Checks the data set and the configuration for every problem that would make
+    /// `get_outliers()` return an `Err`, rather than stopping at the first, such as for showing a
+    /// user a complete list of fixes for their data.  The `NAN`s are reported with their indices in
+    /// the data set, as an `OutlierError::ContainsNansAt`, unless `with_skip_nans()` is used.  The
+    /// size required by `with_min_size()` is checked against the data set after the options that
+    /// remove values from it are applied.  The problems are returned in the order above; if there
+    /// are none, `Ok(())` is returned.
+    pub fn validate(&self) -> Result<(), Vec<OutlierError>> {
+        let mut errors = Vec::new();
+
+        if self.error_on_empty && self.data_set.is_empty() {
+            errors.push(OutlierError::EmptyDataSet);
+        }
+
+        if self.strategy.is_none() {
+            if let Err(error) = self
+                .detection_method
+                .validate(self.k_value.min(self.upper_k_value()))
+            {
+                errors.push(error);
+            }
+        }
+
+        if matches!(self.min_retention, Some(min_retention) if !(0.0..=1.0).contains(&min_retention))
+        {
+            errors.push(OutlierError::InvalidRetentionFraction);
+        }
+
+        if matches!(self.assumed_contamination, Some(fraction) if !(0.0..0.5).contains(&fraction)) {
+            errors.push(OutlierError::InvalidContaminationFraction);
+        }
+
+        if let Some(fixed_fences) = self.fixed_fences {
+            if fixed_fences.lower.is_nan()
+                || fixed_fences.upper.is_nan()
+                || fixed_fences.lower > fixed_fences.upper
+            {
+                errors.push(OutlierError::InvalidFences);
+            }
+        }
+
+        if matches!(self.clamp, Some((min, max)) if min.is_nan() || max.is_nan() || min > max) {
+            errors.push(OutlierError::InvalidClampBounds);
+        }
+
+        if let Some(measurement_errors) = &self.measurement_errors {
+            if measurement_errors.len() != self.data_set.len() {
+                errors.push(OutlierError::MismatchedMeasurementErrors {
+                    values: self.data_set.len(),
+                    errors: measurement_errors.len(),
+                });
+            }
+
+            if measurement_errors
+                .iter()
+                .any(|error| error.is_nan() || *error < 0.0)
+            {
+                errors.push(OutlierError::InvalidMeasurementError);
+            }
+        }
+
+        if let Some(&index) = self
+            .excluded_indices
+            .iter()
+            .flatten()
+            .find(|&&index| index >= self.data_set.len())
+        {
+            errors.push(OutlierError::IndexOutOfRange {
+                index,
+                len: self.data_set.len(),
+            });
+        }
+
+        let nan_indices: Vec<usize> = (0..self.data_set.len())
+            .filter(|&index| to_f64(&self.data_set[index]).is_nan())
+            .collect();
+
+        if !nan_indices.is_empty() && !self.skip_nans {
+            errors.push(OutlierError::ContainsNansAt(nan_indices));
+        }
+
+        if !self.infinities_as_outliers && self.data_set.iter().any(|x| to_f64(x).is_infinite()) {
+            errors.push(OutlierError::ContainsInfinities);
+        }
+
+        if self.log_transform && self.data_set.iter().any(|data| to_f64(data) <= 0.0) {
+            errors.push(OutlierError::ContainsNonPositiveValues);
+        }
+
+        if let Some(min_size) = self.min_size {
+            // Only the size is needed, so the problems found above are set aside, and preparing the
+            // data set then can't fail; the values are removed as `get_outliers()` would remove them
+            let mut prepared = self.clone();
+            prepared.measurement_errors = None;
+            prepared.excluded_indices = prepared.excluded_indices.map(|excluded_indices| {
+                excluded_indices
+                    .into_iter()
+                    .filter(|&index| index < self.data_set.len())
+                    .collect()
+            });
+            prepared.skip_nans = true;
+            prepared.infinities_as_outliers = true;
+
+            if prepared.prepare_data_set().is_ok() && prepared.data_set.len() < min_size {
+                errors.push(OutlierError::InsufficientData {
+                    required: min_size,
+                    found: prepared.data_set.len(),
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors
+                .into_iter()
+                .map(|error| self.label_error(error))
+                .collect())
+        }
+    }
+
+    /// Indicates whether the data set has outliers.  This method is useful when one only needs to
+    /// know if a data set contains outliers, but isn't concerned with their details.  This method
+    /// short circuits; if any outliers exist, the moment the first one is found, the method
+    /// immediately returns with `true`, else, it returns `false`.
+    pub fn has_outliers(mut self) -> Result<bool, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+
+        for position in 0..self.data_set.len() {
+            if self.classify_position(position, lower_fence, upper_fence) != Ordering::Equal {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Indicates whether the data set has upper outliers, as [`get_outliers()`](Self::get_outliers)
+    /// would partition them, such as for alerting on latency spikes while ignoring unusually fast
+    /// responses.  Only the largest value is compared against the upper fence, unless measurement
+    /// errors were given.
+    pub fn has_upper_outliers(self) -> Result<bool, OutlierError> {
+        self.has_outliers_on_side(Ordering::Greater)
+    }
+
+    /// Indicates whether the data set has lower outliers, as [`get_outliers()`](Self::get_outliers)
+    /// would partition them.  Only the smallest value is compared against the lower fence, unless
+    /// measurement errors were given.
+    pub fn has_lower_outliers(self) -> Result<bool, OutlierError> {
+        self.has_outliers_on_side(Ordering::Less)
+    }
+
+    fn has_outliers_on_side(mut self, side: Ordering) -> Result<bool, OutlierError> {
+        // Both fences are computed, since either one is scaled by a spread, such as the
+        // interquartile range, that depends on both ends of the data set
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let len = self.data_set.len();
+
+        // A measurement error can move a value past its neighbors, so the most extreme value on
+        // the side isn't necessarily the first to cross the fence
+        let positions = match (&self.measurement_errors, side) {
+            (Some(_), _) => 0..len,
+            (None, Ordering::Less) => 0..len.min(1),
+            (None, _) => len.saturating_sub(1)..len,
+        };
+
+        Ok(positions
+            .into_iter()
+            .any(|position| self.classify_position(position, lower_fence, upper_fence) == side))
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// only collects the upper outliers, such as for monitoring latency spikes.
+    pub fn upper_outliers(self) -> Result<Vec<T>, OutlierError> {
+        self.values_on_side(Ordering::Greater)
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// only collects the lower outliers.
+    pub fn lower_outliers(self) -> Result<Vec<T>, OutlierError> {
+        self.values_on_side(Ordering::Less)
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// only collects the non-outliers, such as for computing statistics on the trimmed data set
+    /// without allocating the outlier partitions.
+    pub fn trimmed(self) -> Result<Vec<T>, OutlierError> {
+        self.values_on_side(Ordering::Equal)
+    }
+
+    fn values_on_side(mut self, side: Ordering) -> Result<Vec<T>, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+
+        if self.preserve_order {
+            self.restore_original_order();
+        }
+
+        let sides: Vec<Ordering> = (0..self.data_set.len())
+            .map(|position| self.classify_position(position, lower_fence, upper_fence))
+            .collect();
+
+        Ok(self
+            .data_set
+            .into_iter()
+            .zip(sides)
+            .filter(|&(_, data_side)| data_side == side)
+            .map(|(data, _)| round_value(data, self.output_precision))
+            .collect())
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, and
+    /// returns each outlier paired with its distance beyond the fence it crossed, such as for
+    /// ranking the outliers by severity.  The lower outliers come first, followed by the upper
+    /// outliers, each in ascending order of value.
+    pub fn get_outliers_with_distance(mut self) -> Result<Vec<(T, f64)>, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let sides: Vec<Ordering> = (0..self.data_set.len())
+            .map(|position| self.classify_position(position, lower_fence, upper_fence))
+            .collect();
+
+        // The data set is sorted, so the lower outliers already come before the upper outliers
+        let outliers_with_distance = self
+            .data_set
+            .into_iter()
+            .zip(sides)
+            .filter_map(|(data, side)| {
+                let value = to_f64(&data);
+                let distance = match side {
+                    Ordering::Less => lower_fence - value,
+                    Ordering::Equal => return None,
+                    Ordering::Greater => value - upper_fence,
+                };
+
+                Some((
+                    round_value(data, self.output_precision),
+                    round_to_precision(distance, self.output_precision),
+                ))
+            })
+            .collect();
+
+        Ok(outliers_with_distance)
+    }
+
+    /// Returns the outlier that lies the farthest beyond the fence it crossed, as measured by
+    /// `get_outliers_with_distance()`, or `None` if there are no outliers, such as for alerting on
+    /// only the worst value of a data set.  Ties go to the smallest of the outliers.
+    pub fn most_extreme_outlier(self) -> Result<Option<T>, OutlierError> {
+        let most_extreme_outlier = self.get_outliers_with_distance()?.into_iter().fold(
+            None,
+            |most_extreme: Option<(T, f64)>, (data, distance)| match most_extreme {
+                Some((_, max_distance)) if max_distance >= distance => most_extreme,
+                _ => Some((data, distance)),
+            },
+        );
+
+        Ok(most_extreme_outlier.map(|(data, _)| data))
+    }
+
+    /// Returns the number of lower outliers and the number of upper outliers, as
+    /// [`get_outliers()`](Self::get_outliers) would partition them, without allocating any
+    /// partitions.
+    pub fn count_outliers(mut self) -> Result<(usize, usize), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let mut lower_outlier_count = 0;
+        let mut upper_outlier_count = 0;
+
+        for position in 0..self.data_set.len() {
+            match self.classify_position(position, lower_fence, upper_fence) {
+                Ordering::Less => lower_outlier_count += 1,
+                Ordering::Equal => {}
+                Ordering::Greater => upper_outlier_count += 1,
+            }
+        }
+
+        Ok((lower_outlier_count, upper_outlier_count))
+    }
+
+    /// Returns the fraction of the data set that [`get_outliers()`](Self::get_outliers) classifies
+    /// as outliers, from `0.0` to `1.0`, such as for a dashboard.  Values removed by an option
+    /// aren't counted, and an empty data set returns `0.0`.
+    pub fn outlier_fraction(mut self) -> Result<f64, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let len = self.data_set.len();
+
+        if len == 0 {
+            return Ok(0.0);
+        }
+
+        let outlier_count = (0..len)
+            .filter(|&position| {
+                self.classify_position(position, lower_fence, upper_fence) != Ordering::Equal
+            })
+            .count();
+
+        Ok(outlier_count as f64 / len as f64)
+    }
+
+    /// Returns the `k_value` that the fences of [`get_outliers()`](Self::get_outliers) are computed
+    /// with, which differs from the configured one if `with_min_retention()` widened it.  With
+    /// `with_k_values()`, the lower fence's is returned.
+    pub fn effective_k_value(mut self) -> Result<f64, OutlierError> {
+        self.compute_fence_basis()
+            .and_then(|fence_basis| {
+                let min_k_value = self.min_retention_k_value(&fence_basis)?;
+                Ok(fence_basis.k_value.max(min_k_value))
+            })
+            .map_err(|error| self.label_error(error))
+    }
+
+    fn partition(mut self, lower_fence: f64, upper_fence: f64) -> (Vec<T>, Vec<T>, Vec<T>) {
+        if self.preserve_order {
+            self.restore_original_order();
+        }
+
+        let output_precision = self.output_precision;
+        let push = move |mut partitions: (Vec<T>, Vec<T>, Vec<T>), (data, side): (T, Ordering)| {
+            let (lower_outliers, non_outliers, upper_outliers) = &mut partitions;
+            let output_data = round_value(data, output_precision);
+
+            match side {
+                Ordering::Less => lower_outliers.push(output_data),
+                Ordering::Equal => non_outliers.push(output_data),
+                Ordering::Greater => upper_outliers.push(output_data),
+            }
+
+            partitions
+        };
+
+        #[cfg(not(feature = "rayon"))]
+        let partitions = {
+            let sides: Vec<Ordering> = (0..self.data_set.len())
+                .map(|position| self.classify_position(position, lower_fence, upper_fence))
+                .collect();
+
+            self.data_set
+                .into_iter()
+                .zip(sides)
+                .fold(Default::default(), push)
+        };
+
+        // Each thread partitions a contiguous chunk of the data set, and the chunks are joined in
+        // order, so the partitions are the same as those of the sequential loop
+        #[cfg(feature = "rayon")]
+        let partitions = {
+            let sides: Vec<Ordering> = (0..self.data_set.len())
+                .into_par_iter()
+                .map(|position| self.classify_position(position, lower_fence, upper_fence))
+                .collect();
+
+            self.data_set
+                .into_par_iter()
+                .zip(sides)
+                .fold(Default::default, push)
+                .reduce(Default::default, |mut partitions, chunk_partitions| {
+                    partitions.0.extend(chunk_partitions.0);
+                    partitions.1.extend(chunk_partitions.1);
+                    partitions.2.extend(chunk_partitions.2);
+                    partitions
+                })
+        };
+
+        partitions
+    }
+
+    // Classifies the value at `position` of the data set as a lower outlier (`Less`), a non-outlier
+    // (`Equal`), or an upper outlier (`Greater`), accounting for any measurement error
+    fn classify_position(&self, position: usize, lower_fence: f64, upper_fence: f64) -> Ordering {
+        let data = &self.data_set[position];
+        let (lower_comparison, upper_comparison) = match self.measurement_error_at(position) {
+            Some(error) => {
+                let value = to_f64(data);
+
+                (
+                    (value + error).partial_cmp(&lower_fence),
+                    (value - error).partial_cmp(&upper_fence),
+                )
+            }
+            None => (
+                compare_to_fence(data, lower_fence),
+                compare_to_fence(data, upper_fence),
+            ),
+        };
+
+        side_of_fences(
+            lower_comparison,
+            upper_comparison,
+            self.fences_are_inclusive(lower_fence, upper_fence),
+        )
+    }
+
+    // The measurement error of the value at `position` of the prepared data set, if there are any
+    fn measurement_error_at(&self, position: usize) -> Option<f64> {
+        match (&self.measurement_errors, &self.original_indices) {
+            (Some(measurement_errors), Some(original_indices)) => {
+                Some(measurement_errors[original_indices[position]])
+            }
+            _ => None,
+        }
+    }
+
+    fn side(&self, value: f64, lower_fence: f64, upper_fence: f64) -> Ordering {
+        side_of_fences(
+            value.partial_cmp(&lower_fence),
+            value.partial_cmp(&upper_fence),
+            self.fences_are_inclusive(lower_fence, upper_fence),
+        )
+    }
+
+    // Coinciding fences, such as those of a data set whose values are all identical, leave no room
+    // for non-outliers if they are inclusive, so they are always treated as exclusive
+    fn fences_are_inclusive(&self, lower_fence: f64, upper_fence: f64) -> bool {
+        self.inclusive_fences && lower_fence < upper_fence
+    }
+
+    fn get_fences(&mut self) -> Result<(f64, f64), OutlierError> {
+        self.compute_fences()
+            .map_err(|error| self.label_error(error))
+    }
+
+    // Computes the fences, as `get_fences()` does, and then restores the values that remain after
+    // the options that remove values are applied to the order they were passed in
+    fn get_fences_in_original_order(&mut self) -> Result<(f64, f64), OutlierError> {
+        self.preserve_order = true;
+        let fences = self.get_fences()?;
+        self.restore_original_order();
+
+        Ok(fences)
+    }
+
+    fn label_error(&self, error: OutlierError) -> OutlierError {
+        match &self.label {
+            Some(label) => OutlierError::Labeled {
+                label: label.clone(),
+                error: Box::new(error),
+            },
+            None => error,
+        }
+    }
+
+    fn compute_fences(&mut self) -> Result<(f64, f64), OutlierError> {
+        let prepared_fences = self.prepare_fences()?;
+
+        Ok(self.fences_at(&prepared_fences, |fence_basis| {
+            (fence_basis.k_value, fence_basis.upper_k_value)
+        }))
+    }
+
+    // Prepares the data set, and computes everything that the fences depend on other than the
+    // `k_value`s, so that the fences can be computed at several `k_value`s with `fences_at()`
+    // without preparing the data set again
+    fn prepare_fences(&mut self) -> Result<PreparedFences, OutlierError> {
+        if self.error_on_empty && self.data_set.is_empty() {
+            return Err(OutlierError::EmptyDataSet);
+        }
+
+        if let Some(fixed_fences) = self.fixed_fences {
+            if fixed_fences.lower.is_nan()
+                || fixed_fences.upper.is_nan()
+                || fixed_fences.lower > fixed_fences.upper
+            {
+                return Err(OutlierError::InvalidFences);
+            }
+
+            self.prepare_data_set()?;
+            return Ok(PreparedFences::Final(
+                fixed_fences.lower,
+                fixed_fences.upper,
+            ));
+        }
+
+        match self.strategy.clone() {
+            Some(strategy) => {
+                let central_range = self.central_range()?;
+
+                if self.data_set.len() < 2 {
+                    return Ok(PreparedFences::Final(f64::NEG_INFINITY, f64::INFINITY));
+                }
+
+                let sorted_data: Vec<f64> = self.data_set[central_range]
+                    .iter()
+                    .map(|data| self.transform(to_f64(data)))
+                    .collect();
+                let (lower_fence, upper_fence) = strategy.fences(&sorted_data)?;
+
+                if lower_fence.is_nan() || upper_fence.is_nan() || lower_fence > upper_fence {
+                    return Err(OutlierError::InvalidFences);
+                }
+
+                Ok(PreparedFences::Transformed(lower_fence, upper_fence))
+            }
+            None => {
+                let fence_basis = self.compute_fence_basis()?;
+
+                // Quartiles, and any other spread, are meaningless for fewer than two values, so,
+                // rather than relying on the degenerate fences that they would produce, no value
+                // is made an outlier
+                if self.data_set.len() < 2 {
+                    return Ok(PreparedFences::Final(f64::NEG_INFINITY, f64::INFINITY));
+                }
+
+                let min_k_value = self.min_retention_k_value(&fence_basis)?;
+
+                Ok(PreparedFences::Basis {
+                    fence_basis,
+                    min_k_value,
+                })
+            }
+        }
+    }
+
+    // Computes the fences from `prepared_fences`, extending them from the anchors by the lower and
+    // upper `k_value`s that `k_values` picks from the fence basis
+    fn fences_at(
+        &self,
+        prepared_fences: &PreparedFences,
+        k_values: impl Fn(&FenceBasis) -> (f64, f64),
+    ) -> (f64, f64) {
+        let (lower_fence, upper_fence) = match prepared_fences {
+            PreparedFences::Final(lower_fence, upper_fence) => return (*lower_fence, *upper_fence),
+            PreparedFences::Transformed(lower_fence, upper_fence) => (*lower_fence, *upper_fence),
+            PreparedFences::Basis {
+                fence_basis,
+                min_k_value,
+            } => {
+                let (k_value, upper_k_value) = k_values(fence_basis);
+
+                (
+                    fence_basis.lower_anchor - k_value.max(*min_k_value) * fence_basis.spread,
+                    fence_basis.upper_anchor + upper_k_value.max(*min_k_value) * fence_basis.spread,
+                )
+            }
+        };
+        let (lower_fence, upper_fence) = if self.log_transform {
+            (lower_fence.exp(), upper_fence.exp())
+        } else {
+            (lower_fence, upper_fence)
+        };
+
+        match self.min_gap {
+            Some(min_gap) => self.widen_fences_to_gaps(lower_fence, upper_fence, min_gap),
+            None => (lower_fence, upper_fence),
+        }
+    }
+
+    // Widens the fences outwards, one value at a time, until they reach a gap between consecutive
+    // values that exceeds `min_gap`; the data set must already be sorted
+    fn widen_fences_to_gaps(&self, lower_fence: f64, upper_fence: f64, min_gap: f64) -> (f64, f64) {
+        let value = |index: usize| to_f64(&self.data_set[index]);
+        let first_non_outlier = self
+            .data_set
+            .iter()
+            .position(|data| to_f64(data) >= lower_fence);
+        let last_non_outlier = self
+            .data_set
+            .iter()
+            .rposition(|data| to_f64(data) <= upper_fence);
+
+        let (mut lower_index, mut upper_index) = match (first_non_outlier, last_non_outlier) {
+            (Some(lower_index), Some(upper_index)) if lower_index <= upper_index => {
+                (lower_index, upper_index)
+            }
+            _ => return (lower_fence, upper_fence),
+        };
+
+        while lower_index > 0 && value(lower_index) - value(lower_index - 1) <= min_gap {
+            lower_index -= 1;
+        }
+
+        while upper_index + 1 < self.data_set.len()
+            && value(upper_index + 1) - value(upper_index) <= min_gap
+        {
+            upper_index += 1;
+        }
+
+        (
+            lower_fence.min(value(lower_index)),
+            upper_fence.max(value(upper_index)),
+        )
+    }
+
+    fn compute_fence_basis(&mut self) -> Result<FenceBasis, OutlierError> {
+        self.detection_method
+            .validate(self.k_value.min(self.upper_k_value()))?;
+        self.prepare_data_set()?;
+        self.prepared_fence_basis()
+    }
+
+    // Computes the fence basis, as `compute_fence_basis()` does, from a data set that has already
+    // been prepared
+    fn prepared_fence_basis(&self) -> Result<FenceBasis, OutlierError> {
+        let central_range = self.prepared_central_range()?;
+        let central_data_set = &self.data_set[central_range];
+
+        if self.log_transform {
+            let log_data_set: Vec<f64> = central_data_set
+                .iter()
+                .map(|data| to_f64(data).ln())
+                .collect();
+
+            return Ok(self.detection_method.fence_basis(
+                &log_data_set,
+                self.k_value,
+                self.upper_k_value(),
+                self.quartile_method,
+            ));
+        }
+
+        Ok(self.detection_method.fence_basis(
+            central_data_set,
+            self.k_value,
+            self.upper_k_value(),
+            self.quartile_method,
+        ))
+    }
+
+    // Prepares the data set, and returns the range of it that the fences are computed from, which
+    // excludes the infinities and any assumed contamination
+    fn central_range(&mut self) -> Result<Range<usize>, OutlierError> {
+        self.prepare_data_set()?;
+        self.prepared_central_range()
+    }
+
+    fn prepared_central_range(&self) -> Result<Range<usize>, OutlierError> {
+        if let Some(min_size) = self.min_size {
+            if self.data_set.len() < min_size {
+                return Err(OutlierError::InsufficientData {
+                    required: min_size,
+                    found: self.data_set.len(),
+                });
+            }
+        }
+
+        if self.log_transform && self.data_set.iter().any(|data| to_f64(data) <= 0.0) {
+            return Err(OutlierError::ContainsNonPositiveValues);
+        }
+
+        // The infinities are sorted to the ends of the data set, so the finite values lie between them
+        let negative_infinity_count = self
+            .data_set
+            .iter()
+            .take_while(|x| to_f64(*x) == f64::NEG_INFINITY)
+            .count();
+        let positive_infinity_count = self
+            .data_set
+            .iter()
+            .rev()
+            .take_while(|x| to_f64(*x) == f64::INFINITY)
+            .count();
+        let finite_range = negative_infinity_count..self.data_set.len() - positive_infinity_count;
+
+        match self.assumed_contamination {
+            Some(fraction) if (0.0..0.5).contains(&fraction) => {
+                let central_range = central_range(&self.data_set[finite_range.clone()], fraction);
+                Ok(
+                    finite_range.start + central_range.start
+                        ..finite_range.start + central_range.end,
+                )
+            }
+            Some(_) => Err(OutlierError::InvalidContaminationFraction),
+            None => Ok(finite_range),
+        }
+    }
+
+    // Maps a value onto the scale that the fence basis is computed on, as set by
+    // `with_log_transform()`
+    fn transform(&self, value: f64) -> f64 {
+        if self.log_transform {
+            value.ln()
+        } else {
+            value
+        }
+    }
+
+    // Applies the options that remove values from the data set, validates what remains, and sorts it
+    fn prepare_data_set(&mut self) -> Result<(), OutlierError> {
+        // The original positions are carried through the sort, so that they can be restored
+        if self.preserve_order && self.original_indices.is_none() {
+            self.original_indices = Some((0..self.data_set.len()).collect());
+        }
+
+        if let Some(measurement_errors) = &self.measurement_errors {
+            if measurement_errors.len() != self.data_set.len() {
+                return Err(OutlierError::MismatchedMeasurementErrors {
+                    values: self.data_set.len(),
+                    errors: measurement_errors.len(),
+                });
+            }
+
+            if measurement_errors
+                .iter()
+                .any(|error| error.is_nan() || *error < 0.0)
+            {
+                return Err(OutlierError::InvalidMeasurementError);
+            }
+        }
+
+        // The indices refer to the data set as it was passed in, so they are applied first
+        if let Some(excluded_indices) = self.excluded_indices.take() {
+            let len = self.data_set.len();
+            let mut is_excluded = vec![false; len];
+
+            for index in excluded_indices {
+                if index >= len {
+                    return Err(OutlierError::IndexOutOfRange { index, len });
+                }
+
+                is_excluded[index] = true;
+            }
+
+            self.excluded_values = self.remove_values(&is_excluded);
+        }
+
+        if self.skip_nans {
+            let is_nan: Vec<bool> = self.data_set.iter().map(|x| to_f64(x).is_nan()).collect();
+            self.remove_values(&is_nan);
+        }
+
+        if let Some(exclude) = self.exclude.take() {
+            let is_excluded: Vec<bool> = self
+                .data_set
+                .iter()
+                .map(|data| exclude(to_f64(data)))
+                .collect();
+            let excluded_values = self.remove_values(&is_excluded);
+            self.excluded_values.extend(excluded_values);
+        }
+
+        if let Some(min_run) = self.stuck_value_min_run.take() {
+            self.remove_stuck_values(min_run);
+        }
+
+        if let Some((min, max)) = self.clamp {
+            if min.is_nan() || max.is_nan() || min > max {
+                return Err(OutlierError::InvalidClampBounds);
+            }
+
+            for data in self.data_set.iter_mut() {
+                *data = clamp_value(data.clone(), min, max);
+            }
+        }
+
+        let data_set_has_nans = self.data_set.iter().any(|x| to_f64(x).is_nan());
+
+        if data_set_has_nans {
+            return Err(OutlierError::ContainsNans);
+        }
+
+        let data_set_has_infinities = self.data_set.iter().any(|x| to_f64(x).is_infinite());
+
+        if data_set_has_infinities && !self.infinities_as_outliers {
+            return Err(OutlierError::ContainsInfinities);
+        }
+
+        if !self.data_is_sorted {
+            match self.original_indices.take() {
+                Some(original_indices) => {
+                    let mut pairs: Vec<(T, usize)> = std::mem::take(&mut self.data_set)
+                        .into_iter()
+                        .zip(original_indices)
+                        .collect();
+                    sort_values(&mut pairs, |a, b| total_order(&a.0, &b.0));
+                    let (data_set, original_indices) = pairs.into_iter().unzip();
+                    self.data_set = data_set;
+                    self.original_indices = Some(original_indices);
+                }
+                None => sort_values(&mut self.data_set, total_order),
+            }
+
+            self.data_is_sorted = true;
+        } else {
+            debug_assert!(
+                is_non_decreasing(&self.data_set),
+                "`data_is_sorted` was `true`, but the data set is not sorted in ascending order"
+            );
+        }
+
+        Ok(())
+    }
+
+    // Moves every run of `min_run` or more identical, consecutive values out of the data set
+    fn remove_stuck_values(&mut self, min_run: usize) {
+        let mut is_stuck = vec![false; self.data_set.len()];
+        let mut run_start = 0;
+
+        while run_start < self.data_set.len() {
+            let run_value = &self.data_set[run_start];
+            let run_length = self.data_set[run_start..]
+                .iter()
+                .take_while(|&data| data == run_value)
+                .count()
+                .max(1);
+
+            if run_length >= min_run {
+                is_stuck[run_start..run_start + run_length].fill(true);
+            }
+
+            run_start += run_length;
+        }
+
+        self.stuck_values = self.remove_values(&is_stuck);
+    }
+
+    // Reorders the data set, along with its original indices, back into the order it was passed in
+    fn restore_original_order(&mut self) {
+        if let Some(original_indices) = self.original_indices.take() {
+            let mut pairs: Vec<(T, usize)> = std::mem::take(&mut self.data_set)
+                .into_iter()
+                .zip(original_indices)
+                .collect();
+            pairs.sort_by_key(|&(_, original_index)| original_index);
+            let (data_set, original_indices) = pairs.into_iter().unzip();
+            self.data_set = data_set;
+            self.original_indices = Some(original_indices);
+        }
+    }
+
+    // Removes and returns the values flagged in `is_removed`, keeping any original indices aligned
+    // with the remaining values
+    fn remove_values(&mut self, is_removed: &[bool]) -> Vec<T> {
+        if let Some(original_indices) = &mut self.original_indices {
+            let mut is_removed = is_removed.iter();
+            original_indices.retain(|_| !is_removed.next().unwrap());
+        }
+
+        let (removed_values, retained_values) = std::mem::take(&mut self.data_set)
+            .into_iter()
+            .zip(is_removed)
+            .partition::<Vec<(T, &bool)>, _>(|&(_, &is_removed)| is_removed);
+        self.data_set = retained_values.into_iter().map(|(data, _)| data).collect();
+
+        removed_values.into_iter().map(|(data, _)| data).collect()
+    }
+
+    fn upper_k_value(&self) -> f64 {
+        self.upper_k_value.unwrap_or(self.k_value)
+    }
+
+    // Finds the smallest `k_value` whose fences retain at least the `min_retention` fraction of the
+    // data set by computing, for every value, the `k_value` at which a fence would reach it; the
+    // `k_value` of each fence is widened to at least this value
+    fn min_retention_k_value(&self, fence_basis: &FenceBasis) -> Result<f64, OutlierError> {
+        let min_retention = match self.min_retention {
+            Some(min_retention) => min_retention,
+            None => return Ok(0.0),
+        };
+
+        if !(0.0..=1.0).contains(&min_retention) {
+            return Err(OutlierError::InvalidRetentionFraction);
+        }
+
+        let required_count = (min_retention * self.data_set.len() as f64).ceil() as usize;
+
+        if required_count == 0 {
+            return Ok(0.0);
+        }
+
+        let mut required_k_values: Vec<f64> = self
+            .data_set
+            .iter()
+            .map(|data| {
+                let data = self.transform(to_f64(data));
+                let distance =
+                    (fence_basis.lower_anchor - data).max(data - fence_basis.upper_anchor);
+
+                if distance > 0.0 {
+                    distance / fence_basis.spread
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+
+        required_k_values.sort_by(f64::total_cmp);
+        let required_k_value = required_k_values[required_count - 1];
+
+        // A zero spread can't be widened to reach values outside of the anchors
+        if required_k_value.is_infinite() {
+            return Err(OutlierError::UnattainableRetention);
+        }
+
+        // The small margin keeps rounding from placing a fence just inside of the last value that
+        // needs to be retained
+        Ok(required_k_value * (1.0 + 4.0 * f64::EPSILON))
+    }
+}
+
+impl OutlierIdentifier {
+    /// Performs the outlier identification with the modified Z-score test instead of fences, which
+    /// can catch outliers in small or heavily skewed data sets.  A value is an outlier if its
+    /// score, `0.6745 * (value - median) / MAD`, exceeds `threshold` in magnitude; Iglewicz and
+    /// Hoaglin recommend `3.5`.  If the MAD is zero, the mean absolute deviation, scaled by
+    /// `1.2533`, is used instead.  Any allowed infinities are always outliers, and the other
+    /// options don't apply.  `get_outliers_mad()` will return an `Err` if the data set contains one
+    /// or more `NAN`s, or any infinities that aren't allowed.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers_mad(
+        self,
+        threshold: f64,
+    ) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), OutlierError> {
+        const MAD_SCALE: f64 = 0.6745;
+        const MEAN_ABSOLUTE_DEVIATION_SCALE: f64 = 1.2533;
+
+        let sorted_data_set = self
+            .sorted_data_set()
+            .map_err(|error| self.label_error(error))?
+            .into_owned();
+        let median = quantile(&sorted_data_set, 0.5);
+        let mut absolute_deviations: Vec<f64> = sorted_data_set
+            .iter()
+            .map(|data| (data - median).abs())
+            .collect();
+        absolute_deviations.sort_by(f64::total_cmp);
+        let mad = quantile(&absolute_deviations, 0.5);
+
+        let (lower_fence, upper_fence) = if mad > 0.0 {
+            let adjustment_value = threshold * mad / MAD_SCALE;
+            (median - adjustment_value, median + adjustment_value)
+        } else {
+            let mean_absolute_deviation = Mean::mean(&absolute_deviations[..]);
+            let adjustment_value =
+                threshold * MEAN_ABSOLUTE_DEVIATION_SCALE * mean_absolute_deviation;
+            (median - adjustment_value, median + adjustment_value)
+        };
+
+        // The infinities are left out of the median and the MAD, but are still partitioned
+        let (negative_infinities, positive_infinities) = self.infinities();
+        let data_set = [negative_infinities, sorted_data_set, positive_infinities].concat();
+
+        Ok(OutlierIdentifier {
+            data_set,
+            measurement_errors: None,
+            original_indices: None,
+            ..self
+        }
+        .partition(lower_fence, upper_fence))
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// further splits the non-outliers at the quartiles and the median, such as for drawing each
+    /// region of a box plot as a separate series.
+    pub fn partition_detailed(mut self) -> Result<DetailedPartition, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let (lower_quartile, upper_quartile) = self.quartile_method.quartiles(&self.data_set);
+        let median = quantile(&self.data_set, 0.5);
+
+        let mut detailed_partition = DetailedPartition {
+            lower: Vec::new(),
+            below_lower_quartile: Vec::new(),
+            lower_quartile_to_median: Vec::new(),
+            median_to_upper_quartile: Vec::new(),
+            above_upper_quartile: Vec::new(),
+            upper: Vec::new(),
+        };
+
+        for (position, &data) in self.data_set.iter().enumerate() {
+            let side = self.classify_position(position, lower_fence, upper_fence);
+            let bucket = if side == Ordering::Less {
+                &mut detailed_partition.lower
+            } else if side == Ordering::Greater {
+                &mut detailed_partition.upper
+            } else if data < lower_quartile {
+                &mut detailed_partition.below_lower_quartile
+            } else if data < median {
+                &mut detailed_partition.lower_quartile_to_median
+            } else if data <= upper_quartile {
+                &mut detailed_partition.median_to_upper_quartile
+            } else {
+                &mut detailed_partition.above_upper_quartile
+            };
+
+            bucket.push(round_to_precision(data, self.output_precision));
+        }
+
+        Ok(detailed_partition)
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, but
+    /// splits the outliers into mild and extreme ones, as Tukey's original method does.  The outer
+    /// fences extend twice as far from the anchors as the inner ones, so the default `k_value` of
+    /// `1.5` places them at Tukey's `3.0`.  Fences that aren't scaled by a spread, such as those of
+    /// a strategy, make every outlier extreme.
+    pub fn get_outliers_tiered(mut self) -> Result<TieredPartition, OutlierError> {
+        let prepared_fences = self
+            .prepare_fences()
+            .map_err(|error| self.label_error(error))?;
+        let (inner_lower_fence, inner_upper_fence) = self
+            .fences_at(&prepared_fences, |fence_basis| {
+                (fence_basis.k_value, fence_basis.upper_k_value)
+            });
+        let (outer_lower_fence, outer_upper_fence) = self
+            .fences_at(&prepared_fences, |fence_basis| {
+                (2.0 * fence_basis.k_value, 2.0 * fence_basis.upper_k_value)
+            });
+
+        let mut tiered_partition = TieredPartition {
+            extreme_lower: Vec::new(),
+            mild_lower: Vec::new(),
+            non_outliers: Vec::new(),
+            mild_upper: Vec::new(),
+            extreme_upper: Vec::new(),
+        };
+
+        for (position, &data) in self.data_set.iter().enumerate() {
+            let inner_side = self.classify_position(position, inner_lower_fence, inner_upper_fence);
+            let outer_side = self.classify_position(position, outer_lower_fence, outer_upper_fence);
+            let bucket = match (inner_side, outer_side) {
+                (_, Ordering::Less) => &mut tiered_partition.extreme_lower,
+                (Ordering::Less, _) => &mut tiered_partition.mild_lower,
+                (_, Ordering::Greater) => &mut tiered_partition.extreme_upper,
+                (Ordering::Greater, _) => &mut tiered_partition.mild_upper,
+                (Ordering::Equal, Ordering::Equal) => &mut tiered_partition.non_outliers,
+            };
+
+            bucket.push(round_to_precision(data, self.output_precision));
+        }
+
+        Ok(tiered_partition)
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, and
+    /// writes each value and its side, `lower`, `non_outlier`, or `upper`, to `writer` as a record
+    /// in the given `format`, in ascending order of value, rather than collecting the partitions.
+    /// `writer` isn't buffered, so wrapping it in a `BufWriter` is recommended.  `write_outliers()`
+    /// will also return an `Err` if writing fails.
+    pub fn write_outliers<W: Write>(
+        mut self,
+        mut writer: W,
+        format: OutputFormat,
+    ) -> Result<(), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+
+        let mut write_records = || -> std::io::Result<()> {
+            if format == OutputFormat::Csv {
+                writeln!(writer, "value,side")?;
+            }
+
+            for (position, &data) in self.data_set.iter().enumerate() {
+                let side = match self.classify_position(position, lower_fence, upper_fence) {
+                    Ordering::Less => "lower",
+                    Ordering::Equal => "non_outlier",
+                    Ordering::Greater => "upper",
+                };
+                let value = round_to_precision(data, self.output_precision);
+
+                match format {
+                    OutputFormat::Csv => writeln!(writer, "{},{}", value, side)?,
+                    OutputFormat::Jsonl => {
+                        writeln!(writer, "{{\"value\":{},\"side\":\"{}\"}}", value, side)?
+                    }
+                }
+            }
+
+            writer.flush()
+        };
+
+        write_records()
+            .map_err(|error| self.label_error(OutlierError::WriteError(error.to_string())))
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, and
+    /// writes every value to `writer` as CSV, under a `value,category` header, with the categories
+    /// `lower`, `non`, and `upper`, such as for piping the results into a spreadsheet.  For an
+    /// empty data set, only the header is written.  `write_csv()` will also return an `Err` if
+    /// writing fails.
+    pub fn write_csv<W: Write>(mut self, mut writer: W) -> Result<(), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+
+        let mut write_records = || -> std::io::Result<()> {
+            writeln!(writer, "value,category")?;
+
+            for (position, &data) in self.data_set.iter().enumerate() {
+                let category = match self.classify_position(position, lower_fence, upper_fence) {
+                    Ordering::Less => "lower",
+                    Ordering::Equal => "non",
+                    Ordering::Greater => "upper",
+                };
+
+                writeln!(
+                    writer,
+                    "{},{}",
+                    round_to_precision(data, self.output_precision),
+                    category
+                )?;
+            }
+
+            writer.flush()
+        };
+
+        write_records()
+            .map_err(|error| self.label_error(OutlierError::WriteError(error.to_string())))
+    }
+
+    /// Performs the outlier identification, as [`get_outliers()`](Self::get_outliers) does, and
+    /// returns every value in its original order, tagged `0` for a lower outlier, `1` for a
+    /// non-outlier, or `2` for an upper outlier, which is simpler to pass across language
+    /// boundaries than partitions.  Values removed by an option are left out.
+    pub fn classify_flat(mut self) -> Result<Vec<(f64, u8)>, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences_in_original_order()?;
+
+        let tagged_data_set = self
+            .data_set
+            .iter()
+            .enumerate()
+            .map(|(position, &data)| {
+                let tag = match self.classify_position(position, lower_fence, upper_fence) {
+                    Ordering::Less => 0,
+                    Ordering::Equal => 1,
+                    Ordering::Greater => 2,
+                };
+
+                (round_to_precision(data, self.output_precision), tag)
+            })
+            .collect();
+
+        Ok(tagged_data_set)
+    }
+
+    /// Winsorizes the data set: returns every value in its original order, with each outlier, as
+    /// classified by [`get_outliers()`](Self::get_outliers), replaced by the fence it crossed.
+    /// Values removed by an option are left out, so that a sentinel value like `-999.0` is never
+    /// replaced by a plausible one.
+    pub fn winsorize(mut self) -> Result<Vec<f64>, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences_in_original_order()?;
+
+        let winsorized_data_set = self
+            .data_set
+            .iter()
+            .enumerate()
+            .map(|(position, &data)| {
+                let winsorized_data =
+                    match self.classify_position(position, lower_fence, upper_fence) {
+                        Ordering::Less => lower_fence,
+                        Ordering::Equal => data,
+                        Ordering::Greater => upper_fence,
+                    };
+
+                round_to_precision(winsorized_data, self.output_precision)
+            })
+            .collect();
+
+        Ok(winsorized_data_set)
+    }
+
+    /// Returns, for every value in its original order, a probability that it is an outlier, as a
+    /// logistic function, sharpened by `steepness`, of how far it lies beyond its nearest fence of
+    /// [`get_outliers()`](Self::get_outliers), relative to the distance between the fences.  With
+    /// `new_with_errors()`, each value's error is subtracted from that distance.  A value on a
+    /// fence scores `0.5`, but every value scores `0.0` if the fences are infinite, as do the
+    /// values on fences that coincide.
+    pub fn soft_classify(mut self, steepness: f64) -> Result<Vec<f64>, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences_in_original_order()?;
+        let fence_width = upper_fence - lower_fence;
+        // Nothing lies beyond fences that are both infinite, whose width is infinite too
+        let fences_are_infinite = lower_fence == f64::NEG_INFINITY && upper_fence == f64::INFINITY;
+
+        let probabilities = self
+            .data_set
+            .iter()
+            .enumerate()
+            .map(|(position, &data)| {
+                if fences_are_infinite {
+                    return 0.0;
+                }
+
+                let error = self.measurement_error_at(position).unwrap_or(0.0);
+                let distance_beyond_fence =
+                    (lower_fence - (data + error)).max((data - error) - upper_fence);
+
+                // Values on fences that coincide are inside of them, not on the edge of a range
+                if distance_beyond_fence == 0.0 {
+                    return if fence_width == 0.0 { 0.0 } else { 0.5 };
+                }
+
+                let normalized_distance = distance_beyond_fence / fence_width;
+                1.0 / (1.0 + (-steepness * normalized_distance).exp())
+            })
+            .collect();
+
+        Ok(probabilities)
+    }
+
+    /// Performs the outlier identification on multimodal data, where a single pair of fences would
+    /// flag a whole legitimate group.  The sorted data set is split recursively, up to `max_depth`
+    /// times, at its largest gap, as long as both sides hold at least four values and the gap
+    /// exceeds `k_value` times the larger of their interquartile ranges, and each cluster is then
+    /// partitioned as [`get_outliers()`](Self::get_outliers) would.
+    pub fn hierarchical_outliers(
+        mut self,
+        max_depth: usize,
+        k_value: f64,
+    ) -> Result<OutlierResult, OutlierError> {
+        self.k_value = k_value;
+        self.get_fences()?;
+
+        // The infinities that `with_infinities_as_outliers()` allows are sorted to the ends of the
+        // data set, and are kept out of the clusters, so that they don't distort the quartiles and
+        // gaps
+        let (negative_infinities, positive_infinities) = self.infinities();
+        let finite_range =
+            negative_infinities.len()..self.data_set.len() - positive_infinities.len();
+        let mut outlier_result = OutlierResult {
+            lower: negative_infinities,
+            non_outliers: Vec::new(),
+            upper: Vec::new(),
+        };
+
+        self.partition_clusters(finite_range, max_depth, k_value, &mut outlier_result);
+        outlier_result.upper.extend(positive_infinities);
+
+        for data in outlier_result
+            .lower
+            .iter_mut()
+            .chain(&mut outlier_result.non_outliers)
+            .chain(&mut outlier_result.upper)
+        {
+            *data = round_to_precision(*data, self.output_precision);
+        }
+
+        Ok(outlier_result)
+    }
+
+    /// Summarizes the outlier identification of [`get_outliers()`](Self::get_outliers) as a flat
+    /// map for exporting as metrics, with exactly the keys `outlier_count`, `lower_outlier_count`,
+    /// `upper_outlier_count`, `lower_fence`, `upper_fence`, `outlier_fraction`, and `median`.
+    pub fn metrics(mut self) -> Result<BTreeMap<&'static str, f64>, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let median = quantile(&self.data_set, 0.5);
+        let sides: Vec<Ordering> = (0..self.data_set.len())
+            .map(|position| self.classify_position(position, lower_fence, upper_fence))
+            .collect();
+        let lower_outlier_count =
+            sides.iter().filter(|&&side| side == Ordering::Less).count() as f64;
+        let upper_outlier_count = sides
+            .iter()
+            .filter(|&&side| side == Ordering::Greater)
+            .count() as f64;
+        let outlier_count = lower_outlier_count + upper_outlier_count;
+        let outlier_fraction = if self.data_set.is_empty() {
+            0.0
+        } else {
+            outlier_count / self.data_set.len() as f64
+        };
+
+        let metrics = [
+            ("outlier_count", outlier_count),
+            ("lower_outlier_count", lower_outlier_count),
+            ("upper_outlier_count", upper_outlier_count),
+            ("lower_fence", lower_fence),
+            ("upper_fence", upper_fence),
+            ("outlier_fraction", outlier_fraction),
+            ("median", median),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name, round_to_precision(value, self.output_precision)))
+        .collect();
+
+        Ok(metrics)
+    }
+
+    /// Repeatedly removes the outlier furthest beyond its nearest fence, and recomputes the fences
+    /// from the remaining values, until none remain, recording each removal as a `RemovalStep`.
+    /// The fences are recomputed with only the detection method and `k_value`, but each value is
+    /// classified as [`get_outliers()`](Self::get_outliers) would.
+    pub fn iterative_removal_trace(mut self) -> Result<Vec<RemovalStep>, OutlierError> {
+        let (mut lower_fence, mut upper_fence) = self.get_fences()?;
+        // Positions are tracked, rather than a slice of the sorted data set, since, with measurement
+        // errors, an outlier isn't necessarily at either end of the remaining values
+        let mut remaining_positions: Vec<usize> = (0..self.data_set.len()).collect();
+        let mut removal_steps = Vec::new();
+
+        loop {
+            // `max_by()` returns the last maximum, so the outliers are searched in reverse, and ties
+            // go to the lower outlier
+            let most_extreme_outlier = remaining_positions
+                .iter()
+                .enumerate()
+                .filter(|&(_, &position)| {
+                    self.classify_position(position, lower_fence, upper_fence) != Ordering::Equal
+                })
+                .map(|(index, &position)| {
+                    let data = self.data_set[position];
+                    (index, (lower_fence - data).max(data - upper_fence))
+                })
+                .rev()
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+
+            let removed_value = match most_extreme_outlier {
+                Some((index, _)) => self.data_set[remaining_positions.remove(index)],
+                None => break,
+            };
+
+            let remaining_values: Vec<f64> = remaining_positions
+                .iter()
+                .map(|&position| self.data_set[position])
+                .collect();
+            let fence_basis = self.detection_method.fence_basis(
+                &remaining_values,
+                self.k_value,
+                self.upper_k_value(),
+                self.quartile_method,
+            );
+            lower_fence = fence_basis.lower_anchor - fence_basis.k_value * fence_basis.spread;
+            upper_fence = fence_basis.upper_anchor + fence_basis.upper_k_value * fence_basis.spread;
+
+            removal_steps.push(RemovalStep {
+                removed_value: round_to_precision(removed_value, self.output_precision),
+                remaining_count: remaining_values.len(),
+                fences: Fences {
+                    lower: round_to_precision(lower_fence, self.output_precision),
+                    upper: round_to_precision(upper_fence, self.output_precision),
+                },
+            });
+        }
+
+        Ok(removal_steps)
+    }
+
+    /// Identifies a distinct, small subpopulation of the data set as outliers, rather than the
+    /// values in its tails.  A mixture of `components` normal distributions is fitted with the
+    /// expectation-maximization algorithm, and the values assigned to a component whose mixing
+    /// weight is below `minority_threshold` are returned, in ascending order, along with any
+    /// allowed infinities.  `mixture_outliers()` will return an `Err` if the data set contains one
+    /// or more `NAN`s, or any infinities that aren't allowed, or if `components` is `0`.
+    pub fn mixture_outliers(
+        self,
+        components: usize,
+        minority_threshold: f64,
+    ) -> Result<Vec<f64>, OutlierError> {
+        if components == 0 {
+            return Err(self.label_error(OutlierError::InvalidComponentCount));
+        }
+
+        let sorted_data_set = self
+            .sorted_data_set()
+            .map_err(|error| self.label_error(error))?;
+        let (mixing_weights, assignments) = fit_gaussian_mixture(&sorted_data_set, components);
+        let (negative_infinities, positive_infinities) = self.infinities();
+
+        let outliers = negative_infinities
+            .into_iter()
+            .chain(
+                sorted_data_set
+                    .iter()
+                    .zip(assignments)
+                    .filter(|&(_, component)| mixing_weights[component] < minority_threshold)
+                    .map(|(&data, _)| data),
+            )
+            .chain(positive_infinities)
+            .map(|data| round_to_precision(data, self.output_precision))
+            .collect();
+
+        Ok(outliers)
+    }
+
+    /// Computes the mean, median, and standard deviation of the data set that the fences of
+    /// [`get_outliers()`](Self::get_outliers) are computed from, and of its non-outliers alone,
+    /// which shows how much the outliers distort each statistic.  Any allowed infinities are left
+    /// out of both.
+    pub fn impact_summary(mut self) -> Result<ImpactSummary, OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let data_set: Vec<f64> = self
+            .data_set
+            .iter()
+            .copied()
+            .filter(|data| data.is_finite())
+            .collect();
+        let (_, non_outliers, _) = self.partition(lower_fence, upper_fence);
+
+        Ok(ImpactSummary {
+            raw: DescriptiveStatistics::from_data(&data_set),
+            cleaned: DescriptiveStatistics::from_data(&non_outliers),
+        })
+    }
+
+    /// Computes the sample standard deviation of the non-outliers of
+    /// [`get_outliers()`](Self::get_outliers), a spread estimate that is robust to outliers, or
+    /// `NAN` if fewer than two remain.
+    pub fn trimmed_std_dev(self) -> Result<f64, OutlierError> {
+        let (_, non_outliers, _) = self.get_outliers()?;
+        Ok(Variance::std_dev(&non_outliers[..]))
+    }
+
+    /// Computes the fences that `with_k_value()` and `fences()` would at each of several
+    /// `k_value`s, such as `1.5` for a warning and `3.0` for a critical alert, while only preparing
+    /// the data set once.  Each `k_value` is returned alongside its fences, in the order given.
+    /// `multi_fences()` will also return an `Err` if any of the `k_value`s is negative.
+    pub fn multi_fences(&self, k_values: &[f64]) -> Result<Vec<(f64, Fences)>, OutlierError> {
+        if k_values.iter().any(|&k_value| k_value < 0.0) {
+            return Err(self.label_error(OutlierError::NegativeKValue));
+        }
+
+        let mut outlier_identifier = self.clone();
+        let prepared_fences = outlier_identifier
+            .prepare_fences()
+            .map_err(|error| self.label_error(error))?;
+        let is_tukey = matches!(self.detection_method, DetectionMethod::Tukey);
+
+        let multi_fences = k_values
+            .iter()
+            .map(|&k_value| {
+                // The other detection methods carry their own multiples of the spread, which the
+                // `k_value` doesn't replace
+                let (lower_fence, upper_fence) =
+                    outlier_identifier.fences_at(&prepared_fences, |fence_basis| {
+                        if is_tukey {
+                            (k_value, self.upper_k_value.unwrap_or(k_value))
+                        } else {
+                            (fence_basis.k_value, fence_basis.upper_k_value)
+                        }
+                    });
+                let fences = Fences {
+                    lower: round_to_precision(lower_fence, self.output_precision),
+                    upper: round_to_precision(upper_fence, self.output_precision),
+                };
+
+                (k_value, fences)
+            })
+            .collect();
+
+        Ok(multi_fences)
+    }
+
+    /// Prepares the data set, as [`get_outliers()`](Self::get_outliers) does, and computes the
+    /// anchors and spread of the detection method once, returning a `SortedData` that evaluates the
+    /// fences and partitions at any `k_value`.  The options that adjust the fences, such as
+    /// `with_min_gap()`, and any measurement errors don't apply.
+    pub fn into_sorted_data(mut self) -> Result<SortedData, OutlierError> {
+        let fence_basis = self
+            .compute_fence_basis()
+            .map_err(|error| self.label_error(error))?;
+
+        Ok(SortedData::new(
+            self.data_set,
+            fence_basis,
+            self.log_transform,
+            self.inclusive_fences,
+            self.output_precision,
+        ))
+    }
+
+    /// Recommends a `k_value` by how stably it classifies the data set.  The data set is shuffled
+    /// with `seed` and split into `folds` folds, and each candidate from `0.5` to `3.0`, in steps
+    /// of `0.25`, is scored by how often the fences computed without a fold agree with those of the
+    /// full data set on that fold's values; ties go to the smallest candidate.
+    /// `cross_validated_k()` will return an `Err` if the data set contains one or more `NAN`s, or
+    /// any infinities that aren't allowed, if the log transform is enabled and it contains
+    /// non-positive values, or if `folds` is less than `2` or greater than the size of the data
+    /// set.
+    pub fn cross_validated_k(&self, folds: usize, seed: u64) -> Result<f64, OutlierError> {
+        const CANDIDATE_COUNT: usize = 11;
+
+        if folds < 2 || folds > self.data_set.len() {
+            return Err(self.label_error(OutlierError::InvalidFoldCount));
+        }
+
+        let sorted_data_set = self
+            .detection_method
+            .validate(0.0)
+            .and_then(|_| self.transformed_sorted_data_set())
+            .map_err(|error| self.label_error(error))?;
+
+        if folds > sorted_data_set.len() {
+            return Err(self.label_error(OutlierError::InvalidFoldCount));
+        }
+
+        let full_fence_basis = self.detection_method.fence_basis(
+            &sorted_data_set,
+            self.k_value,
+            self.upper_k_value(),
+            self.quartile_method,
+        );
+
+        // Assigns every position of the sorted data set to a fold, via a shuffled permutation, so
+        // that each training set can be collected in sorted order
+        let mut permutation: Vec<usize> = (0..sorted_data_set.len()).collect();
+        let mut rng = Rng::new(seed);
+
+        for index in (1..permutation.len()).rev() {
+            permutation.swap(index, rng.next_index(index + 1));
+        }
+
+        let mut fold_of = vec![0; sorted_data_set.len()];
+
+        for (position, &index) in permutation.iter().enumerate() {
+            fold_of[index] = position % folds;
+        }
+
+        let classify = |fence_basis: &FenceBasis, k_value: f64, data: f64| {
+            let adjustment_value = k_value * fence_basis.spread;
+
+            if data < fence_basis.lower_anchor - adjustment_value {
+                0
+            } else if data > fence_basis.upper_anchor + adjustment_value {
+                2
+            } else {
+                1
+            }
+        };
+
+        let candidate_k_values: Vec<f64> = (0..CANDIDATE_COUNT)
+            .map(|index| 0.5 + 0.25 * index as f64)
+            .collect();
+        let mut agreement_counts = vec![0; CANDIDATE_COUNT];
+
+        for fold in 0..folds {
+            let training_data_set: Vec<f64> = sorted_data_set
+                .iter()
+                .zip(&fold_of)
+                .filter(|&(_, &data_fold)| data_fold != fold)
+                .map(|(&data, _)| data)
+                .collect();
+            let training_fence_basis = self.detection_method.fence_basis(
+                &training_data_set,
+                self.k_value,
+                self.upper_k_value(),
+                self.quartile_method,
+            );
+
+            for (&data, _) in sorted_data_set
+                .iter()
+                .zip(&fold_of)
+                .filter(|&(_, &data_fold)| data_fold == fold)
+            {
+                for (&k_value, agreement_count) in
+                    candidate_k_values.iter().zip(&mut agreement_counts)
+                {
+                    if classify(&training_fence_basis, k_value, data)
+                        == classify(&full_fence_basis, k_value, data)
+                    {
+                        *agreement_count += 1;
+                    }
+                }
+            }
+        }
+
+        // `max_by_key()` returns the last maximum, so the candidates are searched in reverse
+        let (best_k_value, _) = candidate_k_values
+            .iter()
+            .zip(&agreement_counts)
+            .rev()
+            .max_by_key(|&(_, &agreement_count)| agreement_count)
+            .unwrap();
+
+        Ok(*best_k_value)
+    }
+
+    /// Indicates how much `value` could grow before it would cross the upper fence of `fences()`,
+    /// in units of the spread that the fences are scaled by, such as the interquartile range, and
+    /// on the log scale with `with_log_transform()`.  The result is negative for an upper outlier.
+    /// `headroom()` will also return an `Err` if `value` is `NAN`, or isn't positive while the log
+    /// transform is enabled.
+    pub fn headroom(&self, value: f64) -> Result<f64, OutlierError> {
+        if value.is_nan() {
+            return Err(self.label_error(OutlierError::ContainsNans));
+        }
+
+        if self.log_transform && value <= 0.0 {
+            return Err(self.label_error(OutlierError::ContainsNonPositiveValues));
+        }
+
+        let mut outlier_identifier = self.clone();
+        let prepared_fences = outlier_identifier
+            .prepare_fences()
+            .map_err(|error| self.label_error(error))?;
+
+        // Fixed fences, and those of a strategy, aren't scaled by a spread, so the spread of the
+        // detection method is measured separately
+        let spread = match &prepared_fences {
+            PreparedFences::Basis { fence_basis, .. } => fence_basis.spread,
+            _ => {
+                self.detection_method
+                    .validate(self.k_value.min(self.upper_k_value()))
+                    .and_then(|_| outlier_identifier.prepared_fence_basis())
+                    .map_err(|error| self.label_error(error))?
+                    .spread
+            }
+        };
+        let (_, upper_fence) = outlier_identifier.fences_at(&prepared_fences, |fence_basis| {
+            (fence_basis.k_value, fence_basis.upper_k_value)
+        });
+
+        Ok((self.transform(upper_fence) - self.transform(value)) / spread)
+    }
+
+    /// Estimates the standard deviation of the data set from its interquartile range, as
+    /// `IQR / 1.349`, which is exact for normally distributed data but, unlike the sample standard
+    /// deviation, is robust to outliers.  The quartiles are those of the values that the fences are
+    /// computed from, using the `QuartileMethod`.  With `with_log_transform()`, the standard
+    /// deviation of the logarithm of the data set is estimated instead.
+    pub fn robust_sigma(&self) -> Result<f64, OutlierError> {
+        let mut outlier_identifier = self.clone();
+        let central_range = outlier_identifier
+            .central_range()
+            .map_err(|error| self.label_error(error))?;
+        let central_data_set: Vec<f64> = outlier_identifier.data_set[central_range]
+            .iter()
+            .map(|&data| self.transform(data))
+            .collect();
+        let (lower_quartile, upper_quartile) = self.quartile_method.quartiles(&central_data_set);
+
+        Ok((upper_quartile - lower_quartile) / NORMAL_IQR_PER_SIGMA)
+    }
+
+    /// Estimates how heavy the upper tail of the data set is with the Hill estimator, from its
+    /// `sqrt(n)` largest values.  Below roughly `2.0`, the tail is heavy enough that Tukey's fences
+    /// will flag many legitimate values, which suggests `with_log_transform()` or a larger
+    /// `k_value`.  `tail_index()` will return an `Err` if the data set contains one or more `NAN`s,
+    /// or any infinities that aren't allowed, or if any of the values used isn't positive.
+    pub fn tail_index(&self) -> Result<f64, OutlierError> {
+        let sorted_data_set = self
+            .sorted_data_set()
+            .map_err(|error| self.label_error(error))?;
+
+        hill_estimator(sorted_data_set.iter().rev().cloned())
+            .map_err(|error| self.label_error(error))
+    }
+
+    /// Estimates how heavy the lower tail of the data set is, as `tail_index()` does for the upper
+    /// tail, from the magnitudes of the `sqrt(n)` smallest values.  `lower_tail_index()` will
+    /// return an `Err` if the data set contains one or more `NAN`s, or any infinities that aren't
+    /// allowed, or if any of the values used by the estimator isn't negative.
+    pub fn lower_tail_index(&self) -> Result<f64, OutlierError> {
+        let sorted_data_set = self
+            .sorted_data_set()
+            .map_err(|error| self.label_error(error))?;
+
+        hill_estimator(sorted_data_set.iter().map(|data| -data))
+            .map_err(|error| self.label_error(error))
+    }
+
+    /// Performs Grubbs' test for a single outlier, which suits small, roughly normal data sets
+    /// better than fences do, returning the value furthest from the mean if it is significant at
+    /// `alpha`, or an allowed infinity without performing the test.  `grubbs_test()` will return an
+    /// `Err` if the data set contains one or more `NAN`s, or any infinities that aren't allowed, if
+    /// it has fewer than three values, or if `alpha` isn't between `0.0` and `1.0`, exclusive.
+    pub fn grubbs_test(&self, alpha: f64) -> Result<Option<f64>, OutlierError> {
+        const MIN_SIZE: usize = 3;
+
+        if !(0.0 < alpha && alpha < 1.0) {
+            return Err(self.label_error(OutlierError::InvalidSignificanceLevel));
+        }
+
+        if self.data_set.iter().any(|x| x.is_nan()) {
+            return Err(self.label_error(OutlierError::ContainsNans));
+        }
+
+        if let Some(infinity) = self
+            .first_infinity()
+            .map_err(|error| self.label_error(error))?
+        {
+            return Ok(Some(infinity));
+        }
+
+        let len = self.data_set.len();
+
+        if len < MIN_SIZE {
+            return Err(self.label_error(OutlierError::InsufficientData {
+                required: MIN_SIZE,
+                found: len,
+            }));
+        }
+
+        let mean = Mean::mean(&self.data_set[..]);
+        let std_dev = Variance::std_dev(&self.data_set[..]);
+
+        if std_dev == 0.0 {
+            return Ok(None);
+        }
+
+        let most_extreme_value = self
+            .data_set
+            .iter()
+            .cloned()
+            .max_by(|a, b| (a - mean).abs().total_cmp(&(b - mean).abs()))
+            .unwrap();
+        let grubbs_statistic = (most_extreme_value - mean).abs() / std_dev;
+
+        let n = len as f64;
+        let students_t = StudentsT::new(0.0, 1.0, n - 2.0).unwrap();
+        let t = inverse_cdf(&students_t, 1.0 - alpha / (2.0 * n));
+        let critical_value = (n - 1.0) / n.sqrt() * (t * t / (n - 2.0 + t * t)).sqrt();
+
+        if grubbs_statistic > critical_value {
+            Ok(Some(round_to_precision(
+                most_extreme_value,
+                self.output_precision,
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Performs the outlier identification on a time series after removing its trend, so that the
+    /// later values of a rising series aren't all flagged as upper outliers.  The trend at each
+    /// index is the median of its neighbors within `period / 2` indices; near the boundaries, where
+    /// that window doesn't fit, the trend is extrapolated from a one-sided window of the same size.
+    /// The fences are computed from the residuals, and the indices of the values whose residual is
+    /// an outlier are returned.  `get_outliers_detrended()` will return an `Err` if the data set
+    /// contains one or more `NAN`s, if `period` is less than `2`, or if the `k_value` is negative.
+    pub fn get_outliers_detrended(&self, period: usize) -> Result<Vec<usize>, OutlierError> {
+        if period < 2 {
+            return Err(self.label_error(OutlierError::InvalidPeriod));
+        }
+
+        if self.data_set.iter().any(|x| x.is_nan()) {
+            return Err(self.label_error(OutlierError::ContainsNans));
+        }
+
+        let len = self.data_set.len();
+        let half_period = period / 2;
+        let median = |values: &[f64]| {
+            let mut values = values.to_vec();
+            values.sort_by(f64::total_cmp);
+            quantile(&values, 0.5)
+        };
+
+        let residuals: Vec<f64> = (0..len)
+            .map(|index| {
+                let data = self.data_set[index];
+
+                if index >= half_period && index + half_period < len {
+                    let neighbors = [
+                        &self.data_set[index - half_period..index],
+                        &self.data_set[index + 1..=index + half_period],
+                    ]
+                    .concat();
+
+                    return data - median(&neighbors);
+                }
+
+                // Near the boundaries, the centered window doesn't fit, so the trend is extended to
+                // the value through the medians of the nearer and farther halves of a one-sided
+                // window on the longer side, which lie on the trend at the centers of those halves
+                let neighbors: Vec<f64> = if index < len - 1 - index {
+                    self.data_set[index + 1..]
+                        .iter()
+                        .take(2 * half_period)
+                        .copied()
+                        .collect()
+                } else {
+                    self.data_set[..index]
+                        .iter()
+                        .rev()
+                        .take(2 * half_period)
+                        .copied()
+                        .collect()
+                };
+                let radius = half_period.min(neighbors.len() / 2);
+
+                if radius == 0 {
+                    return 0.0;
+                }
+
+                let nearer_median = median(&neighbors[..radius]);
+                let farther_median = median(&neighbors[radius..2 * radius]);
+                let slope = (farther_median - nearer_median) / radius as f64;
+
+                data - (nearer_median - slope * (radius + 1) as f64 / 2.0)
+            })
+            .collect();
+
+        outlier_indices(&residuals, self.k_value).map_err(|error| self.label_error(error))
+    }
+
+    /// Performs the Hampel filter on a time series, flagging each value that is more than `n_sigma`
+    /// scaled MADs from the median of the window around it, which holds up to `window` neighbors on
+    /// each side, such as for cleaning a signal whose level drifts.  The indices of the flagged
+    /// values are returned.  `hampel_filter()` will return an `Err` if the data set contains one or
+    /// more `NAN`s, if `window` is `0`, or if `n_sigma` is negative.
+    pub fn hampel_filter(&self, window: usize, n_sigma: f64) -> Result<Vec<usize>, OutlierError> {
+        const MAD_TO_STD_DEV: f64 = 1.4826;
+
+        if window == 0 {
+            return Err(self.label_error(OutlierError::InvalidWindow));
+        }
+
+        if n_sigma < 0.0 {
+            return Err(self.label_error(OutlierError::NegativeKValue));
+        }
+
+        if self.data_set.iter().any(|x| x.is_nan()) {
+            return Err(self.label_error(OutlierError::ContainsNans));
+        }
+
+        let len = self.data_set.len();
+        let flagged_indices = (0..len)
+            .filter(|&index| {
+                let mut window_values = self.data_set
+                    [index.saturating_sub(window)..(index + window + 1).min(len)]
+                    .to_vec();
+                window_values.sort_by(f64::total_cmp);
+                let median = quantile(&window_values, 0.5);
+
+                let mut absolute_deviations: Vec<f64> = window_values
+                    .iter()
+                    .map(|data| (data - median).abs())
+                    .collect();
+                absolute_deviations.sort_by(f64::total_cmp);
+                let mad = quantile(&absolute_deviations, 0.5);
+
+                (self.data_set[index] - median).abs() > n_sigma * MAD_TO_STD_DEV * mad
+            })
+            .collect();
+
+        Ok(flagged_indices)
+    }
+
+    /// Performs Dixon's Q test for a single outlier in a data set of `3` to `30` values, returning
+    /// the smallest or largest value if its gap to its neighbor, relative to the range, is
+    /// significant at `alpha`, which must be `0.10`, `0.05`, or `0.01`.  Allowed infinities are
+    /// handled as by `grubbs_test()`.  `dixon_q_test()` will return an `Err` if the data set
+    /// contains one or more `NAN`s, or any infinities that aren't allowed, if its size is out of
+    /// range, or if `alpha` isn't tabulated.
+    pub fn dixon_q_test(&self, alpha: f64) -> Result<Option<f64>, OutlierError> {
+        const MIN_SIZE: usize = 3;
+        const MAX_SIZE: usize = 30;
+
+        let alpha_index = match [0.10, 0.05, 0.01].iter().position(|&level| level == alpha) {
+            Some(alpha_index) => alpha_index,
+            None => return Err(self.label_error(OutlierError::InvalidSignificanceLevel)),
+        };
+
+        let sorted_data_set = self
+            .sorted_data_set()
+            .map_err(|error| self.label_error(error))?;
+
+        if let Some(infinity) = self
+            .first_infinity()
+            .map_err(|error| self.label_error(error))?
+        {
+            return Ok(Some(infinity));
+        }
+
+        let len = sorted_data_set.len();
+
+        if len < MIN_SIZE {
+            return Err(self.label_error(OutlierError::InsufficientData {
+                required: MIN_SIZE,
+                found: len,
+            }));
+        }
+
+        if len > MAX_SIZE {
+            return Err(self.label_error(OutlierError::ExcessiveData {
+                maximum: MAX_SIZE,
+                found: len,
+            }));
+        }
+
+        let range = sorted_data_set[len - 1] - sorted_data_set[0];
+
+        if range == 0.0 {
+            return Ok(None);
+        }
+
+        let lower_q = (sorted_data_set[1] - sorted_data_set[0]) / range;
+        let upper_q = (sorted_data_set[len - 1] - sorted_data_set[len - 2]) / range;
+        let (q_statistic, suspect_value) = if upper_q >= lower_q {
+            (upper_q, sorted_data_set[len - 1])
+        } else {
+            (lower_q, sorted_data_set[0])
+        };
+
+        if q_statistic > DIXON_Q_CRITICAL_VALUES[len - MIN_SIZE][alpha_index] {
+            Ok(Some(round_to_precision(
+                suspect_value,
+                self.output_precision,
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Estimates, via bootstrap resampling with `seed`, the probability that a sample the size of
+    /// the data set contains a value deviating from its median at least as much as `value` does, as
+    /// `(1 + count) / (1 + iterations)`.  A small p-value indicates that `value` is unlikely to
+    /// have come from the same distribution.  `outlier_pvalue()` will return an `Err` if the data
+    /// set is empty or if it or `value` is `NAN`.
+    pub fn outlier_pvalue(
+        &self,
+        value: f64,
+        iterations: usize,
+        seed: u64,
+    ) -> Result<f64, OutlierError> {
+        if value.is_nan() || self.data_set.iter().any(|x| x.is_nan()) {
+            return Err(self.label_error(OutlierError::ContainsNans));
+        }
+
+        if self.data_set.is_empty() {
+            return Err(self.label_error(OutlierError::EmptyDataSet));
+        }
+
+        let observed_deviation = (value - Median::median(&self.data_set[..])).abs();
+        let mut rng = Rng::new(seed);
+        let mut resample = vec![0.0; self.data_set.len()];
+        let mut count = 0;
+
+        for _ in 0..iterations {
+            for resampled_value in resample.iter_mut() {
+                *resampled_value = self.data_set[rng.next_index(self.data_set.len())];
+            }
+
+            let median = Median::median(&resample[..]);
+            let max_deviation = resample
+                .iter()
+                .map(|x| (x - median).abs())
+                .fold(0.0, f64::max);
+
+            if max_deviation >= observed_deviation {
+                count += 1;
+            }
+        }
+
+        Ok((1 + count) as f64 / (1 + iterations) as f64)
+    }
+
+    // Recursively splits the sorted data set, within `positions`, at significant gaps and partitions
+    // each resulting cluster
+    fn partition_clusters(
+        &self,
+        positions: Range<usize>,
+        max_depth: usize,
+        k_value: f64,
+        outlier_result: &mut OutlierResult,
+    ) {
+        const MIN_CLUSTER_SIZE: usize = 4;
+
+        let sorted_data = &self.data_set[positions.clone()];
+
+        if max_depth > 0 && sorted_data.len() >= 2 * MIN_CLUSTER_SIZE {
+            let split_index = (MIN_CLUSTER_SIZE..=sorted_data.len() - MIN_CLUSTER_SIZE)
+                .max_by(|&a, &b| {
+                    let gap_a = sorted_data[a] - sorted_data[a - 1];
+                    let gap_b = sorted_data[b] - sorted_data[b - 1];
+                    gap_a.total_cmp(&gap_b)
+                })
+                .unwrap();
+
+            let (left, right) = sorted_data.split_at(split_index);
+            let gap = right[0] - left[left.len() - 1];
+            let (left_lower_quartile, left_upper_quartile) = quartiles(left);
+            let (right_lower_quartile, right_upper_quartile) = quartiles(right);
+            let spread = (left_upper_quartile - left_lower_quartile)
+                .max(right_upper_quartile - right_lower_quartile);
+
+            if gap > k_value * spread {
+                let split_position = positions.start + split_index;
+                self.partition_clusters(
+                    positions.start..split_position,
+                    max_depth - 1,
+                    k_value,
+                    outlier_result,
+                );
+                self.partition_clusters(
+                    split_position..positions.end,
+                    max_depth - 1,
+                    k_value,
+                    outlier_result,
+                );
+                return;
+            }
+        }
+
+        let (lower_quartile, upper_quartile) = quartiles(sorted_data);
+        let quartile_adjustment_value = k_value * (upper_quartile - lower_quartile);
+        let lower_fence = lower_quartile - quartile_adjustment_value;
+        let upper_fence = upper_quartile + quartile_adjustment_value;
+
+        for position in positions {
+            let data = self.data_set[position];
+
+            match self.classify_position(position, lower_fence, upper_fence) {
+                Ordering::Less => outlier_result.lower.push(data),
+                Ordering::Equal => outlier_result.non_outliers.push(data),
+                Ordering::Greater => outlier_result.upper.push(data),
+            }
+        }
+    }
+
+    // Takes the natural logarithm of the sorted data set if the log transform is enabled, which
+    // leaves it sorted
+    fn transformed_sorted_data_set(&self) -> Result<Cow<'_, [f64]>, OutlierError> {
+        let sorted_data_set = self.sorted_data_set()?;
+
+        if !self.log_transform {
+            return Ok(sorted_data_set);
+        }
+
+        if sorted_data_set.iter().any(|&data| data <= 0.0) {
+            return Err(OutlierError::ContainsNonPositiveValues);
+        }
+
+        Ok(Cow::Owned(
+            sorted_data_set.iter().map(|data| data.ln()).collect(),
+        ))
+    }
+
+    // The infinities allowed by `with_infinities_as_outliers()` are left out, as they are when the
+    // fences are computed
+    fn sorted_data_set(&self) -> Result<Cow<'_, [f64]>, OutlierError> {
+        if self.data_set.iter().any(|x| x.is_nan()) {
+            return Err(OutlierError::ContainsNans);
+        }
+
+        let data_set_has_infinities = self.first_infinity()?.is_some();
+
+        if self.data_is_sorted {
+            debug_assert!(
+                is_non_decreasing(&self.data_set),
+                "`data_is_sorted` was `true`, but the data set is not sorted in ascending order"
+            );
+
+            if !data_set_has_infinities {
+                return Ok(Cow::Borrowed(&self.data_set));
+            }
+        }
+
+        let mut sorted_data_set: Vec<f64> = self
+            .data_set
+            .iter()
+            .copied()
+            .filter(|data| data.is_finite())
+            .collect();
+
+        if !self.data_is_sorted {
+            sorted_data_set.sort_by(f64::total_cmp);
+        }
+
+        Ok(Cow::Owned(sorted_data_set))
+    }
+
+    // Returns the first infinity of the data set, which is an `Err` unless infinities are treated as
+    // outliers
+    fn first_infinity(&self) -> Result<Option<f64>, OutlierError> {
+        match self.data_set.iter().find(|data| data.is_infinite()) {
+            Some(_) if !self.infinities_as_outliers => Err(OutlierError::ContainsInfinities),
+            infinity => Ok(infinity.copied()),
+        }
+    }
+
+    // Splits the infinities that `sorted_data_set()` leaves out into the negative and positive ones
+    fn infinities(&self) -> (Vec<f64>, Vec<f64>) {
+        self.data_set
+            .iter()
+            .filter(|data| data.is_infinite())
+            .partition(|&&data| data < 0.0)
+    }
+}
+
+// The fences extend outwards from the anchors by `k_value` multiples of the spread for the lower
+// fence, and by `upper_k_value` multiples for the upper fence
+struct FenceBasis {
+    lower_anchor: f64,
+    upper_anchor: f64,
+    spread: f64,
+    k_value: f64,
+    upper_k_value: f64,
+}
+
+// What the fences are computed from once the data set has been prepared
+enum PreparedFences {
+    // Fences that are used as they are, such as those of `with_fixed_fences()`
+    Final(f64, f64),
+    // Fences on the scale of `with_log_transform()`, such as those of a strategy, that the log
+    // transform and `with_min_gap()` are still applied to
+    Transformed(f64, f64),
+    Basis {
+        fence_basis: FenceBasis,
+        min_k_value: f64,
+    },
+}
+
+/// A custom way of computing the fences, for use with `OutlierIdentifier::with_strategy()`, such as
+/// for a detection method that this crate doesn't provide.
+pub trait OutlierStrategy {
+    /// Computes the lower and upper fences from `sorted_data`, which is sorted in ascending order,
+    /// holds at least two values, and contains no `NAN`s or infinities.  The values below the lower
+    /// fence and above the upper fence are the outliers, as with the built-in detection methods.
+    fn fences(&self, sorted_data: &[f64]) -> Result<(f64, f64), OutlierError>;
+}
+
+/// The method used to compute the fences.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DetectionMethod {
+    /// Tukey's method, the default: the fences are `lower_quartile - k_value * IQR` and
+    /// `upper_quartile + k_value * IQR`, using the `OutlierIdentifier`'s `k_value`.
+    Tukey,
+    /// The fences are `center - k * spread` and `center + k * spread`, where the spread is the
+    /// `upper` percentile minus the `lower` percentile.  The percentiles are given from `0.0` to
+    /// `100.0`, and `lower` must be less than `upper`.  This variant ignores the
+    /// `OutlierIdentifier`'s `k_value` in favor of `k`.  Some notable combinations are:
+    ///
+    /// - `lower: 10.0, upper: 90.0, center: CenterKind::Median`: fences around the median, scaled
+    ///   by the interdecile range.
+    /// - `lower: 25.0, upper: 75.0, center: CenterKind::Midpoint`: fences around the midhinge,
+    ///   scaled by the IQR.  This is equivalent to `Tukey` with a `k_value` of `k - 0.5`.
+    /// - `lower: 25.0, upper: 75.0, center: CenterKind::Median`: fences around the median, scaled
+    ///   by the IQR, which, unlike `Tukey`, are symmetric even when the quartiles aren't.
+    InterPercentile {
+        lower: f64,
+        upper: f64,
+        center: CenterKind,
+        k: f64,
+    },
+    /// Fits a Student's t-distribution with `df` degrees of freedom to the data set, and places the
+    /// fences where each of the fitted distribution's tails holds `tail_prob` of its probability.
+    /// The location and scale are fitted with the expectation-maximization algorithm, which
+    /// down-weights distant values, so the fit is robust to the heavy tails that a normal-based
+    /// method would be distorted by.  A smaller `df` produces heavier tails, and, as `df` grows,
+    /// the distribution approaches the normal distribution.  `df` must be positive and `tail_prob`
+    /// must be between `0.0` and `0.5`, exclusive.  This variant ignores the
+    /// `OutlierIdentifier`'s `k_value`.
+    StudentT { df: f64, tail_prob: f64 },
+    /// The fences are the `lower` and `upper` percentiles themselves, so the values below the
+    /// `lower` percentile and above the `upper` percentile are the outliers.  The percentiles are
+    /// given from `0.0` to `100.0`, and `lower` must not be greater than `upper`.  This variant
+    /// ignores the `OutlierIdentifier`'s `k_value`.
+    Percentile { lower: f64, upper: f64 },
+    /// The classic Z-score rule: the fences are `mean - n_std * std_dev` and
+    /// `mean + n_std * std_dev`, where `std_dev` is the sample standard deviation, so the values
+    /// more than `n_std` standard deviations from the mean are the outliers.  Unlike `Tukey`, the
+    /// mean and the standard deviation are themselves pulled by the outliers, so this is best
+    /// suited to approximately normal data.  If every value is identical, or there are fewer than
+    /// two values, the standard deviation is taken to be `0.0` and every value is a non-outlier.
+    /// `n_std` must not be negative.  This variant ignores the `OutlierIdentifier`'s `k_value`.
+    ZScore { n_std: f64 },
+}
+
+/// The center that `DetectionMethod::InterPercentile` places its fences around.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CenterKind {
+    /// The median of the data set.
+    Median,
+    /// The midpoint between the `lower` and `upper` percentiles.
+    Midpoint,
+    /// The mean of the data set, which, unlike the other centers, isn't robust to outliers.
+    Mean,
+}
+
+/// How the quartiles that `DetectionMethod::Tukey` places its fences around are computed.  The
+/// methods only differ in how they interpolate between the values of the sorted data set, so they
+/// agree on large data sets, but can produce noticeably different fences for small ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuartileMethod {
+    /// R's type 8 quantiles, which are approximately median-unbiased regardless of the
+    /// distribution, the default.  These match `statrs`' `quantile()`, R's
+    /// `quantile(x, type = 8)`, and NumPy's `percentile(x, method="median_unbiased")`.
+    #[default]
+    MedianUnbiased,
+    /// Tukey's hinges: the medians of the lower and upper halves of the data set, where the median
+    /// is included in both halves when the data set has an odd length.  These match R's
+    /// `fivenum()` and `boxplot()`.
+    Tukey,
+    /// R's type 7 quantiles, which linearly interpolate between the closest ranks.  These match
+    /// R's `quantile()`, NumPy's `percentile()`, pandas' `quantile()`, and Excel's
+    /// `QUARTILE.INC()`, with their default settings.
+    Linear,
+    /// The average of the two values that `Linear` interpolates between.  These match NumPy's
+    /// `percentile(x, method="midpoint")` and pandas' `quantile(interpolation="midpoint")`.
+    Midpoint,
+    /// R's type 6 quantiles, which interpolate between ranks as if the data set were extended by
+    /// one value on each side.  These match R's `quantile(x, type = 6)`, NumPy's
+    /// `percentile(x, method="weibull")`, Excel's `QUARTILE.EXC()`, SPSS, and Minitab.
+    Exclusive,
+}
+
+impl QuartileMethod {
+    fn quartiles<T: ToPrimitive>(self, sorted_data: &[T]) -> (f64, f64) {
+        match self {
+            QuartileMethod::MedianUnbiased => quartiles(sorted_data),
+            QuartileMethod::Tukey => {
+                let len = sorted_data.len();
+
+                (
+                    quantile(&sorted_data[..len.div_ceil(2)], 0.5),
+                    quantile(&sorted_data[len / 2..], 0.5),
+                )
+            }
+            QuartileMethod::Linear | QuartileMethod::Midpoint | QuartileMethod::Exclusive => (
+                self.quantile(sorted_data, 0.25),
+                self.quantile(sorted_data, 0.75),
+            ),
+        }
+    }
+
+    // Interpolates at the zero-based position `h` of the sorted data set, clamped to its ends
+    fn quantile<T: ToPrimitive>(self, sorted_data: &[T], tau: f64) -> f64 {
+        if sorted_data.is_empty() {
+            return f64::NAN;
+        }
+
+        let len = sorted_data.len();
+        let h = match self {
+            QuartileMethod::Exclusive => (len as f64 + 1.0) * tau - 1.0,
+            _ => (len as f64 - 1.0) * tau,
+        }
+        .clamp(0.0, (len - 1) as f64);
+        let lower = to_f64(&sorted_data[h.floor() as usize]);
+        let upper = to_f64(&sorted_data[h.ceil() as usize]);
+
+        match self {
+            QuartileMethod::Midpoint => (lower + upper) / 2.0,
+            _ => lower + (h - h.floor()) * (upper - lower),
+        }
+    }
+}
+
+impl DetectionMethod {
+    fn validate(&self, k_value: f64) -> Result<(), OutlierError> {
+        match *self {
+            DetectionMethod::Tukey => {
+                if k_value < 0.0 {
+                    return Err(OutlierError::NegativeKValue);
+                }
+            }
+            DetectionMethod::InterPercentile {
+                lower, upper, k, ..
+            } => {
+                if k < 0.0 {
+                    return Err(OutlierError::NegativeKValue);
+                }
+
+                if !(0.0 <= lower && lower < upper && upper <= 100.0) {
+                    return Err(OutlierError::InvalidPercentiles);
+                }
+            }
+            DetectionMethod::Percentile { lower, upper } => {
+                if !(0.0 <= lower && lower <= upper && upper <= 100.0) {
+                    return Err(OutlierError::InvalidPercentiles);
+                }
+            }
+            DetectionMethod::StudentT { df, tail_prob } => {
+                if df.is_nan() || df <= 0.0 {
+                    return Err(OutlierError::InvalidDegreesOfFreedom);
+                }
+
+                if !(0.0 < tail_prob && tail_prob < 0.5) {
+                    return Err(OutlierError::InvalidTailProbability);
+                }
+            }
+            DetectionMethod::ZScore { n_std } => {
+                if n_std < 0.0 {
+                    return Err(OutlierError::NegativeKValue);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fence_basis<T: ToPrimitive>(
+        &self,
+        sorted_data: &[T],
+        k_value: f64,
+        upper_k_value: f64,
+        quartile_method: QuartileMethod,
+    ) -> FenceBasis {
+        match *self {
+            DetectionMethod::Tukey => {
+                let (lower_quartile, upper_quartile) = quartile_method.quartiles(sorted_data);
+
+                FenceBasis {
+                    lower_anchor: lower_quartile,
+                    upper_anchor: upper_quartile,
+                    spread: upper_quartile - lower_quartile,
+                    k_value,
+                    upper_k_value,
+                }
+            }
+            DetectionMethod::InterPercentile {
+                lower,
+                upper,
+                center,
+                k,
+            } => {
+                let lower_percentile = quantile(sorted_data, lower / 100.0);
+                let upper_percentile = quantile(sorted_data, upper / 100.0);
+                let center = match center {
+                    CenterKind::Median => quantile(sorted_data, 0.5),
+                    CenterKind::Midpoint => (lower_percentile + upper_percentile) / 2.0,
+                    CenterKind::Mean => mean(sorted_data),
+                };
+
+                FenceBasis {
+                    lower_anchor: center,
+                    upper_anchor: center,
+                    spread: upper_percentile - lower_percentile,
+                    k_value: k,
+                    upper_k_value: k,
+                }
+            }
+            DetectionMethod::Percentile { lower, upper } => FenceBasis {
+                lower_anchor: quantile(sorted_data, lower / 100.0),
+                upper_anchor: quantile(sorted_data, upper / 100.0),
+                spread: 0.0,
+                k_value: 0.0,
+                upper_k_value: 0.0,
+            },
+            DetectionMethod::StudentT { df, tail_prob } => {
+                let sorted_data: Vec<f64> = sorted_data.iter().map(to_f64).collect();
+                let (location, scale) = fit_students_t(&sorted_data, df);
+                let standard_students_t = StudentsT::new(0.0, 1.0, df).unwrap();
+                let k_value = inverse_cdf(&standard_students_t, 1.0 - tail_prob);
+
+                FenceBasis {
+                    lower_anchor: location,
+                    upper_anchor: location,
+                    spread: scale,
+                    k_value,
+                    upper_k_value: k_value,
+                }
+            }
+            DetectionMethod::ZScore { n_std } => {
+                let mean = mean(sorted_data);
+
+                FenceBasis {
+                    lower_anchor: mean,
+                    upper_anchor: mean,
+                    spread: sample_std_dev(sorted_data, mean),
+                    k_value: n_std,
+                    upper_k_value: n_std,
+                }
+            }
+        }
+    }
+}
+
+// Computes the mean incrementally, as `statrs` does, so that the result is the same for `f64` data
+fn mean<T: ToPrimitive>(data: &[T]) -> f64 {
+    let mut mean = 0.0;
+
+    for (index, data) in data.iter().enumerate() {
+        mean += (to_f64(data) - mean) / (index + 1) as f64;
+    }
+
+    if data.is_empty() {
+        f64::NAN
+    } else {
+        mean
+    }
+}
+
+// Computes the sample standard deviation about a precomputed `mean`, which is `0.0`, rather than
+// `NAN`, for fewer than two values, so that such a data set has no Z-score outliers
+fn sample_std_dev<T: ToPrimitive>(data: &[T], mean: f64) -> f64 {
+    if data.len() < 2 {
+        return 0.0;
+    }
+
+    let sum_of_squares: f64 = data.iter().map(|data| (to_f64(data) - mean).powi(2)).sum();
+    (sum_of_squares / (data.len() - 1) as f64).sqrt()
+}
+
+// Fits the location and scale of a Student's t-distribution with `df` degrees of freedom using the
+// expectation-maximization algorithm, starting from the median and the IQR-based scale estimate
+fn fit_students_t(sorted_data: &[f64], df: f64) -> (f64, f64) {
+    const MAX_ITERATIONS: usize = 500;
+    const TOLERANCE: f64 = 1e-12;
+
+    let mut location = quantile(sorted_data, 0.5);
+    let (lower_quartile, upper_quartile) = quartiles(sorted_data);
+    let mut scale = (upper_quartile - lower_quartile) / NORMAL_IQR_PER_SIGMA;
+
+    if scale == 0.0 {
+        scale = Variance::std_dev(sorted_data);
+    }
+
+    if scale.is_nan() || scale <= 0.0 {
+        return (location, 0.0);
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        let weights: Vec<f64> = sorted_data
+            .iter()
+            .map(|&data| {
+                let standardized_value = (data - location) / scale;
+                (df + 1.0) / (df + standardized_value * standardized_value)
+            })
+            .collect();
+        let weight_sum: f64 = weights.iter().sum();
+
+        let next_location = sorted_data
+            .iter()
+            .zip(&weights)
+            .map(|(data, weight)| weight * data)
+            .sum::<f64>()
+            / weight_sum;
+        let next_scale = (sorted_data
+            .iter()
+            .zip(&weights)
+            .map(|(data, weight)| weight * (data - next_location).powi(2))
+            .sum::<f64>()
+            / sorted_data.len() as f64)
+            .sqrt();
+
+        let converged = (next_location - location).abs() <= TOLERANCE * scale
+            && (next_scale - scale).abs() <= TOLERANCE * scale;
+
+        location = next_location;
+        scale = next_scale;
+
+        if converged || scale.is_nan() || scale <= 0.0 {
+            break;
+        }
+    }
+
+    (location, scale)
+}
+
+// Fits a mixture of `components` normal distributions with the expectation-maximization algorithm,
+// and returns the mixing weights alongside the index of the most likely component for each value;
+// every value is assigned to a single component if the data has no spread
+fn fit_gaussian_mixture(sorted_data: &[f64], components: usize) -> (Vec<f64>, Vec<usize>) {
+    const MAX_ITERATIONS: usize = 500;
+    const TOLERANCE: f64 = 1e-10;
+
+    let variance = Variance::variance(sorted_data);
+
+    if variance.is_nan() || variance <= 0.0 {
+        return (vec![1.0], vec![0; sorted_data.len()]);
+    }
+
+    // Keeps a component that collapses onto a single value from producing an infinite density
+    let min_variance = variance * 1e-6;
+    let mut mixing_weights = vec![1.0 / components as f64; components];
+    let mut means: Vec<f64> = (0..components)
+        .map(|component| quantile(sorted_data, (component as f64 + 0.5) / components as f64))
+        .collect();
+    let mut variances = vec![variance / (components * components) as f64; components];
+    let mut responsibilities = vec![vec![0.0; components]; sorted_data.len()];
+    let mut previous_log_likelihood = f64::NEG_INFINITY;
+
+    for _ in 0..MAX_ITERATIONS {
+        let normals: Vec<Normal> = means
+            .iter()
+            .zip(&variances)
+            .map(|(&mean, &variance)| Normal::new(mean, variance.sqrt()).unwrap())
+            .collect();
+        let mut log_likelihood = 0.0;
+
+        for (&data, data_responsibilities) in sorted_data.iter().zip(&mut responsibilities) {
+            for ((responsibility, normal), mixing_weight) in data_responsibilities
+                .iter_mut()
+                .zip(&normals)
+                .zip(&mixing_weights)
+            {
+                *responsibility = mixing_weight.ln() + normal.ln_pdf(data);
+            }
+
+            // The log-sum-exp keeps distant values from underflowing to a zero total density
+            let max_log_density = data_responsibilities
+                .iter()
+                .cloned()
+                .fold(f64::NEG_INFINITY, f64::max);
+            let log_density = max_log_density
+                + data_responsibilities
+                    .iter()
+                    .map(|log_density| (log_density - max_log_density).exp())
+                    .sum::<f64>()
+                    .ln();
+
+            for responsibility in data_responsibilities.iter_mut() {
+                *responsibility = (*responsibility - log_density).exp();
+            }
+
+            log_likelihood += log_density;
+        }
+
+        for component in 0..components {
+            let total_responsibility: f64 = responsibilities
+                .iter()
+                .map(|data_responsibilities| data_responsibilities[component])
+                .sum();
+
+            if total_responsibility <= 0.0 {
+                mixing_weights[component] = 0.0;
+                continue;
+            }
+
+            let mean = sorted_data
+                .iter()
+                .zip(&responsibilities)
+                .map(|(data, data_responsibilities)| data_responsibilities[component] * data)
+                .sum::<f64>()
+                / total_responsibility;
+            let component_variance = sorted_data
+                .iter()
+                .zip(&responsibilities)
+                .map(|(data, data_responsibilities)| {
+                    data_responsibilities[component] * (data - mean).powi(2)
+                })
+                .sum::<f64>()
+                / total_responsibility;
+
+            mixing_weights[component] = total_responsibility / sorted_data.len() as f64;
+            means[component] = mean;
+            variances[component] = component_variance.max(min_variance);
+        }
+
+        if (log_likelihood - previous_log_likelihood).abs() <= TOLERANCE * log_likelihood.abs() {
+            break;
+        }
+
+        previous_log_likelihood = log_likelihood;
+    }
+
+    let assignments = responsibilities
+        .iter()
+        .map(|data_responsibilities| {
+            (0..components)
+                .max_by(|&a, &b| data_responsibilities[a].total_cmp(&data_responsibilities[b]))
+                .unwrap()
+        })
+        .collect();
+
+    (mixing_weights, assignments)
+}
+
+// Computes the Hill estimator of the tail index from values given in descending order, using the
+// `sqrt(n)` largest values as the tail and the next largest value as the threshold
+fn hill_estimator(
+    descending_data: impl ExactSizeIterator<Item = f64>,
+) -> Result<f64, OutlierError> {
+    let len = descending_data.len();
+
+    if len < 2 {
+        return Ok(f64::NAN);
+    }
+
+    let tail_count = ((len as f64).sqrt() as usize).clamp(1, len - 1);
+    let tail: Vec<f64> = descending_data.take(tail_count + 1).collect();
+
+    if tail.iter().any(|&data| data <= 0.0) {
+        return Err(OutlierError::ContainsNonPositiveValues);
+    }
+
+    let threshold = tail[tail_count].ln();
+    let mean_log_excess = tail[..tail_count]
+        .iter()
+        .map(|data| data.ln() - threshold)
+        .sum::<f64>()
+        / tail_count as f64;
+
+    Ok(1.0 / mean_log_excess)
+}
+
+// Inverts the cumulative distribution function of a distribution that is symmetric around zero by
+// bisection, for a probability above `0.5`
+fn inverse_cdf(distribution: &impl Univariate<f64, f64>, probability: f64) -> f64 {
+    let mut lower_bound = 0.0;
+    let mut upper_bound = 1.0;
+
+    while distribution.cdf(upper_bound) < probability {
+        lower_bound = upper_bound;
+        upper_bound *= 2.0;
+    }
+
+    for _ in 0..100 {
+        let midpoint = (lower_bound + upper_bound) / 2.0;
+
+        if distribution.cdf(midpoint) < probability {
+            lower_bound = midpoint;
+        } else {
+            upper_bound = midpoint;
+        }
+    }
+
+    (lower_bound + upper_bound) / 2.0
+}
+
+// Returns the range of the `1 - fraction` of the sorted data closest to its median, which, since the
+// data is sorted, is the window of that size whose furthest value is closest to the median
+fn central_range<T: ToPrimitive>(sorted_data: &[T], fraction: f64) -> Range<usize> {
+    let central_count = ((1.0 - fraction) * sorted_data.len() as f64).ceil() as usize;
+
+    if central_count == 0 || central_count >= sorted_data.len() {
+        return 0..sorted_data.len();
+    }
+
+    let median = quantile(sorted_data, 0.5);
+    let window_start = (0..=sorted_data.len() - central_count)
+        .min_by(|&a, &b| {
+            let radius = |start: usize| {
+                (median - to_f64(&sorted_data[start]))
+                    .max(to_f64(&sorted_data[start + central_count - 1]) - median)
+            };
+            let radius_a = radius(a);
+            let radius_b = radius(b);
+            radius_a.total_cmp(&radius_b)
+        })
+        .unwrap();
+
+    window_start..window_start + central_count
+}
+
+// Rounds a value of any numeric type to `decimals` decimal places, leaving it untouched if it
+// already has no digits beyond that precision, so that integers are never converted
+fn round_value<T: NumCast>(value: T, decimals: Option<u32>) -> T {
+    let unrounded_value = to_f64(&value);
+    let rounded_value = round_to_precision(unrounded_value, decimals);
+
+    if rounded_value == unrounded_value {
+        return value;
+    }
+
+    T::from(rounded_value).unwrap_or(value)
+}
+
+// Values of an integer type are clamped to the bound converted to that type, if it can be
+fn clamp_value<T: NumCast>(value: T, min: f64, max: f64) -> T {
+    let unclamped_value = to_f64(&value);
+
+    if unclamped_value < min {
+        T::from(min).unwrap_or(value)
+    } else if unclamped_value > max {
+        T::from(max).unwrap_or(value)
+    } else {
+        value
+    }
+}
+
+fn round_to_precision(value: f64, decimals: Option<u32>) -> f64 {
+    let decimals = match decimals {
+        Some(decimals) => decimals,
+        None => return value,
+    };
+
+    let scale = 10_f64.powi(decimals.min(i32::MAX as u32) as i32);
+    let rounded_value = (value * scale).round() / scale;
+
+    // Values too large to scale already have no digits at the requested precision
+    if rounded_value.is_finite() {
+        rounded_value
+    } else {
+        value
+    }
+}
+
+// Performs the outlier identification on `values` and returns the indices of the outliers
+fn outlier_indices(values: &[f64], k_value: f64) -> Result<Vec<usize>, OutlierError> {
+    let (lower_fence, upper_fence) = OutlierIdentifier::new(values.to_vec(), false)
+        .with_k_value(k_value)
+        .get_fences()?;
+
+    let indices = values
+        .iter()
+        .enumerate()
+        .filter(|(_, &value)| value < lower_fence || value > upper_fence)
+        .map(|(index, _)| index)
+        .collect();
+
+    Ok(indices)
+}
+
+// The ratio of the interquartile range to the standard deviation of a normal distribution
+const NORMAL_IQR_PER_SIGMA: f64 = 1.349;
+
+// The critical values of Dixon's Q test, from Rorabacher (1991), for data sets of 3 to 30 values at
+// significance levels of 0.10, 0.05, and 0.01
+const DIXON_Q_CRITICAL_VALUES: [[f64; 3]; 28] = [
+    [0.941, 0.970, 0.994],
+    [0.765, 0.829, 0.926],
+    [0.642, 0.710, 0.821],
+    [0.560, 0.625, 0.740],
+    [0.507, 0.568, 0.680],
+    [0.468, 0.526, 0.634],
+    [0.437, 0.493, 0.598],
+    [0.412, 0.466, 0.568],
+    [0.392, 0.444, 0.542],
+    [0.376, 0.426, 0.522],
+    [0.361, 0.410, 0.503],
+    [0.349, 0.396, 0.488],
+    [0.338, 0.384, 0.475],
+    [0.329, 0.374, 0.463],
+    [0.320, 0.365, 0.452],
+    [0.313, 0.356, 0.442],
+    [0.306, 0.349, 0.433],
+    [0.300, 0.342, 0.425],
+    [0.295, 0.337, 0.418],
+    [0.290, 0.331, 0.411],
+    [0.285, 0.326, 0.404],
+    [0.281, 0.321, 0.399],
+    [0.277, 0.317, 0.393],
+    [0.273, 0.312, 0.388],
+    [0.269, 0.308, 0.384],
+    [0.266, 0.305, 0.380],
+    [0.263, 0.301, 0.376],
+    [0.260, 0.298, 0.372],
+];
+
+// Values that can't be represented as an `f64` are treated as `NAN`, so they are rejected along with
+// any actual `NAN`s
+fn to_f64<T: ToPrimitive>(value: &T) -> f64 {
+    value.to_f64().unwrap_or(f64::NAN)
+}
+
+// Classifies a value from its comparisons to the lower and the upper fence, where a `None`, such as
+// from a `NAN` fence, never makes the value an outlier
+fn side_of_fences(
+    lower_comparison: Option<Ordering>,
+    upper_comparison: Option<Ordering>,
+    inclusive_fences: bool,
+) -> Ordering {
+    let beyond = |comparison: Option<Ordering>, side: Ordering| {
+        comparison == Some(side) || (inclusive_fences && comparison == Some(Ordering::Equal))
+    };
+
+    if beyond(lower_comparison, Ordering::Less) {
+        Ordering::Less
+    } else if beyond(upper_comparison, Ordering::Greater) {
+        Ordering::Greater
+    } else {
+        Ordering::Equal
+    }
+}
+
+// Compares a value to a fence without the precision loss of converting the value to an `f64`, which
+// rounds integers beyond `2^53`, so that such an integer could otherwise compare equal to a fence
+// that it is actually beyond.  Every `f64` that large is itself an integer, so, on such a tie, the
+// fence is converted to the value's type instead, and, if it is out of the type's range, the value
+// must lie on the side of the fence towards zero.
+fn compare_to_fence<T: NumCast + PartialOrd>(value: &T, fence: f64) -> Option<Ordering> {
+    match to_f64(value).partial_cmp(&fence) {
+        Some(Ordering::Equal) => match T::from(fence) {
+            Some(fence) => value.partial_cmp(&fence),
+            None if fence > 0.0 => Some(Ordering::Less),
+            None => Some(Ordering::Greater),
+        },
+        comparison => comparison,
+    }
+}
+
+// Orders any two values, even if the type's own ordering is partial, such as for `f64`s, so that
+// sorting never panics; the type's own ordering is used where it is defined, since it is exact for
+// large integers, and `f64::total_cmp()`, which puts positive `NAN`s last, is the fallback
+fn total_order<T: PartialOrd + ToPrimitive>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or_else(|| to_f64(a).total_cmp(&to_f64(b)))
+}
+
+// Only called from `debug_assert!()`s, to catch a caller passing `true` for `data_is_sorted` with
+// unsorted data, which would otherwise silently produce wrong quartiles
+fn is_non_decreasing<T: ToPrimitive>(data: &[T]) -> bool {
+    data.windows(2)
+        .all(|pair| to_f64(&pair[0]) <= to_f64(&pair[1]))
+}
+
+// Sorts with a parallel merge sort when the `rayon` feature is enabled; both sorts are stable, so
+// the results are the same either way
+#[cfg(not(feature = "rayon"))]
+fn sort_values<V>(values: &mut [V], compare: impl Fn(&V, &V) -> Ordering) {
+    values.sort_by(compare);
+}
+
+#[cfg(feature = "rayon")]
+fn sort_values<V: Send>(values: &mut [V], compare: impl Fn(&V, &V) -> Ordering + Sync) {
+    values.par_sort_by(compare);
+}
+
+fn quartiles<T: ToPrimitive>(sorted_data: &[T]) -> (f64, f64) {
+    (quantile(sorted_data, 0.25), quantile(sorted_data, 0.75))
+}
+
+// Uses the same interpolation as `statrs`' `quantile()` (R's type 8), but reads the sorted data
+// directly, since `statrs` partially reorders the data while selecting from it
+fn quantile<T: ToPrimitive>(sorted_data: &[T], tau: f64) -> f64 {
+    if sorted_data.is_empty() {
+        return f64::NAN;
+    }
+
+    let len = sorted_data.len();
+    let h = (len as f64 + 1.0 / 3.0) * tau + 1.0 / 3.0;
+    let index = h as usize;
+
+    if index == 0 {
+        return to_f64(&sorted_data[0]);
+    }
+
+    if index >= len {
+        return to_f64(&sorted_data[len - 1]);
+    }
+
+    let lower = to_f64(&sorted_data[index - 1]);
+    let upper = to_f64(&sorted_data[index]);
+
+    lower + (h - index as f64) * (upper - lower)
+}
+
+#[test]
+fn get_outliers_not_sorted_nan_error() {
+    let data: Vec<f64> = [f64::NAN, f64::NAN].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let results_tuple = outlier_identifier.get_outliers();
+
+    assert!(matches!(results_tuple, Err(OutlierError::ContainsNans)));
+}
+
+#[test]
+fn get_outliers_sorted_nan_error() {
+    let data: Vec<f64> = [3.0, 2.9, 2.8, 33.3, f64::NAN, f64::NAN].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+    let results_tuple = outlier_identifier.get_outliers();
+
+    assert!(matches!(results_tuple, Err(OutlierError::ContainsNans)));
+}
+
+#[test]
+fn get_outliers_empty_data_set() {
+    let data: Vec<f64> = [].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(results_tuple.1, [].to_vec());
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn with_error_on_empty_1() {
+    let data: Vec<f64> = [].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+
+    assert_eq!(
+        outlier_identifier
+            .clone()
+            .with_error_on_empty(true)
+            .get_outliers(),
+        Err(OutlierError::EmptyDataSet)
+    );
+    assert!(outlier_identifier
+        .with_error_on_empty(false)
+        .get_outliers()
+        .is_ok());
+    assert!(OutlierIdentifier::new([1.0].to_vec(), true)
+        .with_error_on_empty(true)
+        .get_outliers()
+        .is_ok());
+}
+
+#[test]
+fn get_outliers_set_of_one() {
+    let data: Vec<f64> = [30.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(results_tuple.1, [30.0].to_vec());
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn get_outliers_set_of_one_is_non_outlier() {
+    let data: Vec<f64> = [30.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true).with_inclusive_fences(true);
+
+    assert_eq!(
+        outlier_identifier.fences().unwrap(),
+        (f64::NEG_INFINITY, f64::INFINITY)
+    );
+    assert_eq!(
+        outlier_identifier.clone().get_outliers().unwrap(),
+        ([].to_vec(), [30.0].to_vec(), [].to_vec())
+    );
+    assert_eq!(
+        outlier_identifier.get_outliers_zscore(0.0).unwrap(),
+        ([].to_vec(), [30.0].to_vec(), [].to_vec())
+    );
+}
+
+#[test]
+fn get_outliers_all_identical() {
+    let data = [5.0; 5].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+
+    assert_eq!(outlier_identifier.fences().unwrap(), (5.0, 5.0));
+    assert_eq!(
+        outlier_identifier.clone().get_outliers().unwrap(),
+        ([].to_vec(), [5.0; 5].to_vec(), [].to_vec())
+    );
+    assert_eq!(
+        outlier_identifier
+            .clone()
+            .with_inclusive_fences(true)
+            .get_outliers()
+            .unwrap(),
+        ([].to_vec(), [5.0; 5].to_vec(), [].to_vec())
+    );
+    assert_eq!(
+        outlier_identifier
+            .with_inclusive_fences(true)
+            .into_sorted_data()
+            .unwrap()
+            .partition_at(1.5)
+            .unwrap(),
+        ([].to_vec(), [5.0; 5].to_vec(), [].to_vec())
+    );
+}
+
+#[test]
+fn get_outliers_set_of_two() {
+    let data: Vec<f64> = [30.0, 90.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(results_tuple.1, [30.0, 90.0].to_vec());
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn get_outliers_none() {
+    let data: Vec<f64> = [1.0, 2.0, 4.0, 10.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(results_tuple.1, [1.0, 2.0, 4.0, 10.0].to_vec());
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn get_outliers_1() {
+    let data = [
+        0.0, 3.0, 3.0, 3.0, 11.0, 12.0, 13.0, 15.0, 19.0, 20.0, 29.0, 40.0, 79.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [0.0, 3.0, 3.0, 3.0, 11.0, 12.0, 13.0, 15.0, 19.0, 20.0, 29.0, 40.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [79.0].to_vec());
+}
+
+#[test]
+fn get_outliers_negative_1() {
+    let data = [
+        29.5, -3.79, 15.0, 11.47, 3.6, 3.6, 19.0, 79.37, 40.7, -23.3, 12.0, 20.113, 13.39,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [-23.3, -3.79, 3.6, 3.6, 11.47, 12.0, 13.39, 15.0, 19.0, 20.113, 29.5, 40.7].to_vec()
+    );
+    assert_eq!(results_tuple.2, [79.37].to_vec());
+}
+
+#[test]
+fn get_outliers_negative_2() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [-62.3].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [43.3, 51.7, 65.43, 67.23, 67.9, 71.02].to_vec()
+    );
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn get_outliers_skip_nans() {
+    let data = [
+        -62.3,
+        67.9,
+        f64::NAN,
+        71.02,
+        43.3,
+        51.7,
+        f64::NAN,
+        65.43,
+        67.23,
+    ]
+    .to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [-62.3].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [43.3, 51.7, 65.43, 67.23, 67.9, 71.02].to_vec()
+    );
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn get_outliers_integers() {
+    let data: Vec<i32> = [10, 12, 11, 15, 11, 14, 13, 17, 12, 22, 14, 11, -40].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false).get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [-40].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [10, 11, 11, 11, 12, 12, 13, 14, 14, 15, 17].to_vec()
+    );
+    assert_eq!(results_tuple.2, [22].to_vec());
+}
+
+#[test]
+fn get_outliers_large_integers_straddling_fences() {
+    // Beyond 2^53, not every integer is an `f64`, so 2^53 + 1 rounds to 2^53, the upper fence, but
+    // it must still be classified as beyond the fence
+    const TWO_POW_53: i64 = 1 << 53;
+    let data = [
+        TWO_POW_53 - 3,
+        TWO_POW_53 - 2,
+        TWO_POW_53 - 1,
+        TWO_POW_53,
+        TWO_POW_53 + 1,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true)
+        .with_fixed_fences((TWO_POW_53 - 2) as f64, TWO_POW_53 as f64);
+
+    assert_eq!(
+        outlier_identifier.clone().get_outliers().unwrap(),
+        (
+            [TWO_POW_53 - 3].to_vec(),
+            [TWO_POW_53 - 2, TWO_POW_53 - 1, TWO_POW_53].to_vec(),
+            [TWO_POW_53 + 1].to_vec()
+        )
+    );
+    assert_eq!(
+        outlier_identifier
+            .with_inclusive_fences(true)
+            .get_outliers()
+            .unwrap(),
+        (
+            [TWO_POW_53 - 3, TWO_POW_53 - 2].to_vec(),
+            [TWO_POW_53 - 1].to_vec(),
+            [TWO_POW_53, TWO_POW_53 + 1].to_vec()
+        )
+    );
+}
+
+#[test]
+fn compare_to_fence_out_of_range() {
+    // `u64::MAX` rounds to 2^64, which is beyond the range of a `u64`
+    let fence = u64::MAX as f64;
+
+    assert_eq!(compare_to_fence(&u64::MAX, fence), Some(Ordering::Less));
+    assert_eq!(compare_to_fence(&0_u64, fence), Some(Ordering::Less));
+}
+
+#[test]
+fn get_outliers_f32() {
+    let data: Vec<f32> = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0, -40.0,
+    ]
+    .to_vec();
+    let results_tuple: (Vec<f32>, Vec<f32>, Vec<f32>) =
+        OutlierIdentifier::new(data, false).get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [-40.0].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [10.0, 11.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 14.0, 15.0, 17.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [22.0].to_vec());
+}
+
+#[test]
+fn get_outliers_f32_nan_error() {
+    let data: Vec<f32> = [1.0, f32::NAN].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false).get_outliers();
+
+    assert!(matches!(results_tuple, Err(OutlierError::ContainsNans)));
+}
+
+#[test]
+fn get_outliers_struct_1() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23, 140.0].to_vec();
+    let outlier_result = OutlierIdentifier::new(data, false)
+        .get_outliers_struct()
+        .unwrap();
+
+    assert_eq!(outlier_result.lower(), [-62.3]);
+    assert_eq!(
+        outlier_result.non_outliers(),
+        [43.3, 51.7, 65.43, 67.23, 67.9, 71.02]
+    );
+    assert_eq!(outlier_result.upper(), [140.0]);
+    assert_eq!(outlier_result.all_outliers(), [-62.3, 140.0].to_vec());
+    assert_eq!(outlier_result.outlier_count(), 2);
+}
+
+#[test]
+fn fences_1() {
+    let data = [0.0, 1.0, 2.0, 3.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_k_value(1.0);
+    let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
+
+    assert!((lower_fence - -1.75).abs() < 1e-10);
+    assert!((upper_fence - 4.75).abs() < 1e-10);
+    assert!(matches!(
+        outlier_identifier.with_k_value(-1.0).fences(),
+        Err(OutlierError::NegativeKValue)
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn outlier_result_serde_round_trip() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23, 140.0].to_vec();
+    let outlier_result = OutlierIdentifier::new(data, false)
+        .get_outliers_struct()
+        .unwrap();
+    let json = serde_json::to_string(&outlier_result).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"lower":[-62.3],"non_outliers":[43.3,51.7,65.43,67.23,67.9,71.02],"upper":[140.0]}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<OutlierResult>(&json).unwrap(),
+        outlier_result
+    );
+}
+
+#[test]
+fn get_outliers_ref_1() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_exclude(|data| data == 43.3);
+    let results_tuple = outlier_identifier.get_outliers_ref().unwrap();
+
+    assert_eq!(results_tuple.0, [-62.3].to_vec());
+    assert_eq!(results_tuple.1, [51.7, 65.43, 67.23, 67.9, 71.02].to_vec());
+    assert_eq!(results_tuple.2, [].to_vec());
+    assert_eq!(
+        outlier_identifier.get_outliers_ref().unwrap(),
+        results_tuple
+    );
+    assert_eq!(outlier_identifier.get_outliers().unwrap(), results_tuple);
+}
+
+#[test]
+fn get_outlier_indices_1() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23, 140.0].to_vec();
+    let indices_tuple = OutlierIdentifier::new(data, false)
+        .get_outlier_indices()
+        .unwrap();
+
+    assert_eq!(indices_tuple.0, [0].to_vec());
+    assert_eq!(indices_tuple.1, [3, 4, 5, 6, 1, 2].to_vec());
+    assert_eq!(indices_tuple.2, [7].to_vec());
+}
+
+#[test]
+fn outlier_mask_1() {
+    let data = [10.0, f64::NAN, 12.0, 11.0, -40.0, 15.0, 11.0, 14.0, 22.0].to_vec();
+    let outlier_mask = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .outlier_mask()
+        .unwrap();
+
+    assert_eq!(
+        outlier_mask,
+        [false, false, false, false, true, false, false, false, true].to_vec()
+    );
+}
+
+#[test]
+fn split_outliers_ordered_1() {
+    let data = [10.0, 12.0, 11.0, -40.0, 15.0, 11.0, 14.0, 22.0].to_vec();
+    let (non_outliers, outliers) = OutlierIdentifier::new(data, false)
+        .split_outliers_ordered()
+        .unwrap();
+
+    assert_eq!(non_outliers, [10.0, 12.0, 11.0, 15.0, 11.0, 14.0].to_vec());
+    assert_eq!(outliers, [(3, -40.0), (7, 22.0)].to_vec());
+}
+
+#[test]
+fn get_outlier_indices_empty_data_set() {
+    let data: Vec<f64> = [].to_vec();
+    let indices_tuple = OutlierIdentifier::new(data, false)
+        .get_outlier_indices()
+        .unwrap();
+
+    assert_eq!(indices_tuple, (Vec::new(), Vec::new(), Vec::new()));
+}
+
+#[test]
+fn check_k_1() {
+    let outlier_identifier =
+        |k_value| OutlierIdentifier::<f64>::new([].to_vec(), true).with_k_value(k_value);
+
+    assert_eq!(
+        outlier_identifier(0.0).check_k(),
+        KAssessment::VeryAggressive
+    );
+    assert_eq!(outlier_identifier(1.5).check_k(), KAssessment::Reasonable);
+    assert_eq!(outlier_identifier(3.0).check_k(), KAssessment::Reasonable);
+    assert_eq!(
+        outlier_identifier(100.0).check_k(),
+        KAssessment::VeryLenient
+    );
+}
+
+#[test]
+fn suggested_k_1() {
+    let outlier_identifier = |len| OutlierIdentifier::new(vec![0.0; len], true);
+
+    assert_eq!(outlier_identifier(10).suggested_k().unwrap(), 1.5);
+    assert!((outlier_identifier(100).suggested_k().unwrap() - 1.5809).abs() < 1e-4);
+    assert!((outlier_identifier(1000).suggested_k().unwrap() - 2.0803).abs() < 1e-4);
+    assert_eq!(
+        outlier_identifier(0).suggested_k(),
+        Err(OutlierError::EmptyDataSet)
+    );
+}
+
+#[test]
+fn get_outliers_mad_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 25.0, 14.0, 11.0, 1.0,
+    ]
+    .to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false)
+        .get_outliers_mad(3.5)
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [1.0].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [10.0, 11.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 14.0, 15.0, 17.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [25.0].to_vec());
+}
+
+#[test]
+fn get_outliers_mad_nan_error() {
+    let data = [1.0, f64::NAN, 3.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false).get_outliers_mad(3.5);
+
+    assert!(matches!(results_tuple, Err(OutlierError::ContainsNans)));
+}
+
+#[test]
+fn asymmetric_k_values() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23, 120.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data.clone(), false)
+        .with_k_values(0.0, 3.0)
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [-62.3, 43.3].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [51.7, 65.43, 67.23, 67.9, 71.02, 120.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [].to_vec());
+
+    let results_tuple = OutlierIdentifier::new(data, false)
+        .with_k_values(1.5, -1.0)
+        .get_outliers();
+
+    assert!(matches!(results_tuple, Err(OutlierError::NegativeKValue)));
+}
+
+#[test]
+fn negative_k_value_error() {
+    let data = [30.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true).with_k_value(-3.0);
+    let results_tuple = outlier_identifier.get_outliers();
+
+    assert!(matches!(results_tuple, Err(OutlierError::NegativeKValue)));
+}
+
+#[test]
+fn trimmed_std_dev_1() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+    let trimmed_std_dev = OutlierIdentifier::new(data, false)
+        .trimmed_std_dev()
+        .unwrap();
+    let expected = Variance::std_dev(&[43.3, 51.7, 65.43, 67.23, 67.9, 71.02][..]);
+
+    assert_eq!(trimmed_std_dev, expected);
+}
+
+#[test]
+fn min_retention_widens_k_value() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 100.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data.clone(), true).with_min_retention(1.0);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(results_tuple.1, data);
+    assert_eq!(results_tuple.2, [].to_vec());
+
+    let effective_k_value = OutlierIdentifier::new(data, true)
+        .with_min_retention(1.0)
+        .effective_k_value()
+        .unwrap();
+
+    assert!(effective_k_value > 1.5);
+}
+
+#[test]
+fn min_retention_already_met() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 100.0].to_vec();
+    let effective_k_value = OutlierIdentifier::new(data, true)
+        .with_min_retention(0.9)
+        .effective_k_value()
+        .unwrap();
+
+    assert_eq!(effective_k_value, 1.5);
+}
+
+#[test]
+fn with_min_size_1() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true).with_min_size(8);
+
+    assert!(outlier_identifier.clone().get_outliers().is_ok());
+    assert_eq!(
+        outlier_identifier.with_min_size(9).get_outliers(),
+        Err(OutlierError::InsufficientData {
+            required: 9,
+            found: 8
+        })
+    );
+}
+
+#[test]
+fn min_retention_unattainable_error() {
+    let data = [5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 100.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, true)
+        .with_min_retention(1.0)
+        .get_outliers();
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::UnattainableRetention)
+    ));
+}
+
+#[test]
+fn tail_index_1() {
+    // Values at the quantiles of a Pareto distribution with a tail index of 2
+    let data: Vec<f64> = (1..=100)
+        .map(|i| (1.0 - i as f64 / 101.0).powf(-0.5))
+        .collect();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let tail_index = outlier_identifier.tail_index().unwrap();
+
+    assert!((tail_index - 2.0).abs() < 0.5);
+    assert!(matches!(
+        outlier_identifier.lower_tail_index(),
+        Err(OutlierError::ContainsNonPositiveValues)
+    ));
+}
+
+#[test]
+fn grubbs_test_1() {
+    let data = [
+        199.31, 199.53, 200.19, 200.82, 201.92, 201.95, 202.18, 245.57,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(outlier_identifier.grubbs_test(0.05).unwrap(), Some(245.57));
+
+    let data = [199.31, 199.53, 200.19, 200.82, 201.92, 201.95, 202.18].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(outlier_identifier.grubbs_test(0.05).unwrap(), None);
+}
+
+#[test]
+fn grubbs_test_insufficient_data_error() {
+    let data = [1.0, 2.0].to_vec();
+    let grubbs_test = OutlierIdentifier::new(data, false).grubbs_test(0.05);
+
+    assert!(matches!(
+        grubbs_test,
+        Err(OutlierError::InsufficientData {
+            required: 3,
+            found: 2
+        })
+    ));
+}
+
+#[test]
+fn dixon_q_test_1() {
+    // The gap between 0.163 and its neighbor is 0.014 of a range of 0.026, a Q of 0.538, which
+    // exceeds the critical value of 0.466 at a significance level of 0.05, but not that of 0.568
+    // at 0.01
+    let data = [
+        0.189, 0.163, 0.187, 0.183, 0.186, 0.182, 0.181, 0.184, 0.181, 0.177,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(outlier_identifier.dixon_q_test(0.05).unwrap(), Some(0.163));
+    assert_eq!(outlier_identifier.dixon_q_test(0.01).unwrap(), None);
+}
+
+#[test]
+fn dixon_q_test_size_errors() {
+    let too_few = OutlierIdentifier::new([1.0, 2.0].to_vec(), false).dixon_q_test(0.05);
+    let too_many =
+        OutlierIdentifier::new((0..31).map(|i| i as f64).collect(), true).dixon_q_test(0.05);
+
+    assert!(matches!(
+        too_few,
+        Err(OutlierError::InsufficientData {
+            required: 3,
+            found: 2
+        })
+    ));
+    assert!(matches!(
+        too_many,
+        Err(OutlierError::ExcessiveData {
+            maximum: 30,
+            found: 31
+        })
+    ));
+}
+
+#[test]
+fn outlier_pvalue_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let extreme_pvalue = outlier_identifier.outlier_pvalue(40.0, 500, 7).unwrap();
+    let typical_pvalue = outlier_identifier.outlier_pvalue(13.0, 500, 7).unwrap();
+
+    assert_eq!(extreme_pvalue, 1.0 / 501.0);
+    assert_eq!(typical_pvalue, 1.0);
+    assert_eq!(
+        outlier_identifier.outlier_pvalue(16.0, 500, 7).unwrap(),
+        outlier_identifier.outlier_pvalue(16.0, 500, 7).unwrap()
+    );
+}
+
+#[test]
+fn outlier_pvalue_empty_data_set_error() {
+    let outlier_identifier = OutlierIdentifier::new([].to_vec(), false);
+    let pvalue = outlier_identifier.outlier_pvalue(1.0, 100, 0);
+
+    assert!(matches!(pvalue, Err(OutlierError::EmptyDataSet)));
+}
+
+#[test]
+fn robust_sigma_1() {
+    let data = [9.0, 1.0, 8.0, 2.0, 7.0, 3.0, 6.0, 4.0, 5.0].to_vec();
+    let robust_sigma = OutlierIdentifier::new(data, false).robust_sigma().unwrap();
+    let interquartile_range = (7.0 + 1.0 / 3.0) - (2.0 + 2.0 / 3.0);
+
+    assert!((robust_sigma - interquartile_range / 1.349).abs() < 1e-10);
+}
+
+#[test]
+fn with_sigma_k_converts_to_k_value() {
+    let data = [1.0, 2.0, 4.0, 10.0].to_vec();
+    let effective_k_value = OutlierIdentifier::new(data, true)
+        .with_sigma_k(2.698)
+        .effective_k_value()
+        .unwrap();
+
+    assert!((effective_k_value - 1.5).abs() < 1e-10);
+}
+
+#[test]
+fn assumed_contamination_resists_masking() {
+    // The cluster of large values inflates the IQR enough to hide itself, unless it is trimmed
+    let data = [
+        10.0, 10.5, 11.0, 11.5, 12.0, 12.5, 13.0, 13.5, 14.0, 14.5, 90.0, 91.0, 92.0, 93.0,
+    ]
+    .to_vec();
+    let results_tuple = OutlierIdentifier::new(data.clone(), true)
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(results_tuple.2, [].to_vec());
+
+    let results_tuple = OutlierIdentifier::new(data, true)
+        .with_assumed_contamination(0.3)
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(results_tuple.2, [90.0, 91.0, 92.0, 93.0].to_vec());
+}
+
+#[test]
+fn assumed_contamination_invalid_fraction_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, true)
+        .with_assumed_contamination(0.5)
+        .get_outliers();
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::InvalidContaminationFraction)
+    ));
+}
+
+#[test]
+fn stuck_value_detection() {
+    let data = [
+        10.0, 12.0, 11.0, 40.0, 40.0, 40.0, 40.0, 40.0, 15.0, 11.0, 14.0, 13.0, 40.0, 12.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_stuck_value_detection(3);
+    let (results_tuple, stuck_values) =
+        outlier_identifier.get_outliers_with_stuck_values().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [10.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 15.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [40.0].to_vec());
+    assert_eq!(stuck_values, [40.0, 40.0, 40.0, 40.0, 40.0].to_vec());
+}
+
+#[test]
+fn measurement_errors_1() {
+    let values = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 25.0, 14.0, 11.0, 24.0,
+    ]
+    .to_vec();
+    let errors = [
+        0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 5.0,
+    ]
+    .to_vec();
+    let results_tuple = OutlierIdentifier::new_with_errors(values, errors, 1.5)
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [10.0, 11.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 14.0, 15.0, 17.0, 24.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [25.0].to_vec());
+}
+
+#[test]
+fn measurement_errors_tiered_and_iterative_removal() {
+    let values = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 25.0, 14.0, 11.0, 24.0,
+    ]
+    .to_vec();
+    let errors = [
+        0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 5.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new_with_errors(values, errors, 1.5);
+    let tiered_partition = outlier_identifier.clone().get_outliers_tiered().unwrap();
+    let removal_steps = outlier_identifier.iterative_removal_trace().unwrap();
+
+    assert!(tiered_partition.non_outliers.contains(&24.0));
+    assert_eq!(
+        [tiered_partition.mild_upper, tiered_partition.extreme_upper].concat(),
+        [25.0].to_vec()
+    );
+    assert_eq!(removal_steps.len(), 1);
+    assert_eq!(removal_steps[0].removed_value, 25.0);
+}
+
+#[test]
+fn measurement_errors_tiered_skip_nans() {
+    let values = [
+        10.0,
+        12.0,
+        11.0,
+        15.0,
+        11.0,
+        f64::NAN,
+        14.0,
+        13.0,
+        17.0,
+        12.0,
+        40.0,
+        14.0,
+        11.0,
+        24.0,
+    ]
+    .to_vec();
+    let errors = [
+        0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 5.0,
+    ]
+    .to_vec();
+    let tiered_partition = OutlierIdentifier::new_with_errors(values, errors, 1.5)
+        .with_skip_nans(true)
+        .get_outliers_tiered()
+        .unwrap();
+
+    assert!(tiered_partition.non_outliers.contains(&24.0));
+    assert!(tiered_partition.mild_upper.is_empty());
+    assert_eq!(tiered_partition.extreme_upper, [40.0].to_vec());
+}
+
+#[test]
+fn measurement_errors_partition_detailed() {
+    let values = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 25.0, 14.0, 11.0, 24.0,
+    ]
+    .to_vec();
+    let errors = [
+        0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 5.0,
+    ]
+    .to_vec();
+    let detailed_partition = OutlierIdentifier::new_with_errors(values, errors, 1.5)
+        .partition_detailed()
+        .unwrap();
+
+    assert_eq!(detailed_partition.upper, [25.0].to_vec());
+    assert!(detailed_partition.above_upper_quartile.contains(&24.0));
+}
+
+#[test]
+fn measurement_errors_write_outliers() {
+    let values = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 25.0, 14.0, 11.0, 24.0,
+    ]
+    .to_vec();
+    let errors = [
+        0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 5.0,
+    ]
+    .to_vec();
+    let mut output = Vec::new();
+    OutlierIdentifier::new_with_errors(values, errors, 1.5)
+        .write_outliers(&mut output, OutputFormat::Jsonl)
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+
+    assert!(output.contains("{\"value\":24,\"side\":\"non_outlier\"}"));
+    assert!(output.contains("{\"value\":25,\"side\":\"upper\"}"));
+}
+
+#[test]
+fn measurement_errors_metrics() {
+    let values = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 25.0, 14.0, 11.0, 24.0,
+    ]
+    .to_vec();
+    let errors = [
+        0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 5.0,
+    ]
+    .to_vec();
+    let metrics = OutlierIdentifier::new_with_errors(values, errors, 1.5)
+        .metrics()
+        .unwrap();
+
+    assert_eq!(metrics["upper_outlier_count"], 1.0);
+    assert_eq!(metrics["outlier_count"], 1.0);
+}
+
+#[test]
+fn merge_1() {
+    let data = [10.0, 12.0, 11.0, 15.0, 11.0, 14.0].to_vec();
+    let other_data = [13.0, 17.0, 12.0, 22.0, 14.0, 11.0].to_vec();
+    let merged_results_tuple = OutlierIdentifier::new(data.clone(), false)
+        .with_k_value(1.0)
+        .merge(OutlierIdentifier::new(other_data.clone(), true).with_k_value(3.0))
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(
+        merged_results_tuple,
+        OutlierIdentifier::new([data, other_data].concat(), false)
+            .with_k_value(1.0)
+            .get_outliers()
+            .unwrap()
+    );
+}
+
+#[test]
+fn merge_measurement_errors() {
+    let data = [10.0, 12.0, 11.0, 15.0, 11.0, 14.0].to_vec();
+    let other_values = [13.0, 17.0, 12.0, 30.0, 14.0, 11.0].to_vec();
+    let other_errors = [0.0, 0.0, 0.0, 20.0, 0.0, 0.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(
+        outlier_identifier
+            .clone()
+            .merge(OutlierIdentifier::new(other_values.clone(), false))
+            .get_outliers()
+            .unwrap()
+            .2,
+        [30.0].to_vec()
+    );
+    assert_eq!(
+        outlier_identifier
+            .merge(OutlierIdentifier::new_with_errors(
+                other_values,
+                other_errors,
+                1.5
+            ))
+            .get_outliers()
+            .unwrap()
+            .2,
+        [].to_vec()
+    );
+}
+
+#[test]
+fn measurement_errors_mismatched_lengths_error() {
+    let results_tuple =
+        OutlierIdentifier::new_with_errors([1.0, 2.0].to_vec(), [0.1].to_vec(), 1.5).get_outliers();
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::MismatchedMeasurementErrors {
+            values: 2,
+            errors: 1
+        })
+    ));
+}
+
+#[test]
+fn exclude_sentinel_values() {
+    let data = [
+        10.0, -999.0, 12.0, 11.0, 15.0, 11.0, 14.0, -999.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let (results_tuple, excluded_values) = OutlierIdentifier::new(data, false)
+        .with_exclude(|data| data == -999.0)
+        .get_outliers_with_excluded()
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [10.0, 11.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 14.0, 15.0, 17.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [22.0].to_vec());
+    assert_eq!(excluded_values, [-999.0, -999.0].to_vec());
+}
+
+#[test]
+fn with_excluded_indices_1() {
+    // The instrument errors at indices 1 and 2 would otherwise widen the IQR enough to hide 22
+    let data = [
+        10.0, 40.0, 45.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let (results_tuple, excluded_values) = outlier_identifier
+        .clone()
+        .with_excluded_indices(&[2, 1])
+        .get_outliers_with_excluded()
+        .unwrap();
+
+    assert_eq!(
+        outlier_identifier.get_outliers().unwrap().2,
+        [40.0, 45.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [22.0].to_vec());
+    assert_eq!(excluded_values, [40.0, 45.0].to_vec());
+}
+
+#[test]
+fn with_excluded_indices_out_of_range_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+
+    assert_eq!(
+        OutlierIdentifier::new(data, false)
+            .with_excluded_indices(&[3])
+            .get_outliers(),
+        Err(OutlierError::IndexOutOfRange { index: 3, len: 3 })
+    );
+}
+
+#[test]
+fn with_clamp_1() {
+    // Readings in Kelvin, where -40.0 and -35.0 are physically impossible
+    let data = [
+        -40.0, 290.0, 295.0, 292.0, 291.0, -35.0, 294.0, 293.0, 296.0, 292.0, 340.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(
+        outlier_identifier
+            .clone()
+            .with_clamp(0.0, 1000.0)
+            .get_outliers(),
+        Ok((
+            [0.0, 0.0].to_vec(),
+            [290.0, 291.0, 292.0, 292.0, 293.0, 294.0, 295.0, 296.0].to_vec(),
+            [340.0].to_vec()
+        ))
+    );
+    assert_eq!(
+        outlier_identifier.with_clamp(280.0, 300.0).get_outliers(),
+        Ok((
+            [280.0, 280.0].to_vec(),
+            [290.0, 291.0, 292.0, 292.0, 293.0, 294.0, 295.0, 296.0, 300.0].to_vec(),
+            [].to_vec()
+        ))
+    );
+}
+
+#[test]
+fn with_clamp_invalid_bounds_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(
+        outlier_identifier
+            .clone()
+            .with_clamp(2.0, 1.0)
+            .get_outliers(),
+        Err(OutlierError::InvalidClampBounds)
+    );
+    assert_eq!(
+        outlier_identifier.with_clamp(f64::NAN, 1.0).get_outliers(),
+        Err(OutlierError::InvalidClampBounds)
+    );
+}
+
+#[test]
+fn inter_percentile_median_center() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 30.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true).with_detection_method(
+        DetectionMethod::InterPercentile {
+            lower: 10.0,
+            upper: 90.0,
+            center: CenterKind::Median,
+            k: 1.0,
+        },
+    );
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [30.0].to_vec());
+}
+
+#[test]
+fn inter_percentile_midpoint_matches_tukey() {
+    let data = [
+        29.5, -3.79, 15.0, 11.47, 3.6, 3.6, 19.0, 79.37, 40.7, -23.3, 12.0, 20.113, 13.39,
+    ]
+    .to_vec();
+    let tukey_results_tuple = OutlierIdentifier::new(data.clone(), false)
+        .get_outliers()
+        .unwrap();
+    let inter_percentile_results_tuple = OutlierIdentifier::new(data, false)
+        .with_detection_method(DetectionMethod::InterPercentile {
+            lower: 25.0,
+            upper: 75.0,
+            center: CenterKind::Midpoint,
+            k: 2.0,
+        })
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(tukey_results_tuple, inter_percentile_results_tuple);
+}
+
+#[test]
+fn inter_percentile_invalid_percentiles_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, true)
+        .with_detection_method(DetectionMethod::InterPercentile {
+            lower: 90.0,
+            upper: 10.0,
+            center: CenterKind::Mean,
+            k: 1.5,
+        })
+        .get_outliers();
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::InvalidPercentiles)
+    ));
+}
+
+#[test]
+fn multi_fences_1() {
+    let data = [9.0, 1.0, 8.0, 2.0, 7.0, 3.0, 6.0, 4.0, 5.0].to_vec();
+    let multi_fences = OutlierIdentifier::new(data, false)
+        .multi_fences(&[0.0, 1.5])
+        .unwrap();
+    let lower_quartile = 2.0 + 2.0 / 3.0;
+    let upper_quartile = 7.0 + 1.0 / 3.0;
+    let interquartile_range = upper_quartile - lower_quartile;
+
+    assert_eq!(multi_fences.len(), 2);
+    assert_eq!(multi_fences[0].0, 0.0);
+    assert!((multi_fences[0].1.lower - lower_quartile).abs() < 1e-10);
+    assert!((multi_fences[0].1.upper - upper_quartile).abs() < 1e-10);
+    assert_eq!(multi_fences[1].0, 1.5);
+    assert!((multi_fences[1].1.lower - (lower_quartile - 1.5 * interquartile_range)).abs() < 1e-10);
+    assert!((multi_fences[1].1.upper - (upper_quartile + 1.5 * interquartile_range)).abs() < 1e-10);
+}
+
+#[test]
+fn get_outliers_percentile_1() {
+    let data: Vec<f64> = (1..=20).map(|i| i as f64).collect();
+    let results_tuple = OutlierIdentifier::new(data, true)
+        .get_outliers_percentile(10.0, 90.0)
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [1.0, 2.0].to_vec());
+    assert_eq!(results_tuple.1.len(), 16);
+    assert_eq!(results_tuple.2, [19.0, 20.0].to_vec());
+}
+
+#[test]
+fn get_outliers_percentile_invalid_percentiles_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, true).get_outliers_percentile(60.0, 40.0);
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::InvalidPercentiles)
+    ));
+}
+
+#[test]
+fn students_t_1() {
+    let data = [
+        9.8, 10.1, 10.0, 9.9, 10.2, 10.3, 9.7, 10.0, 10.1, 9.9, 25.0, 10.4, 9.6, -4.0,
+    ]
+    .to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false)
+        .with_detection_method(DetectionMethod::StudentT {
+            df: 3.0,
+            tail_prob: 0.001,
+        })
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [-4.0].to_vec());
+    assert_eq!(results_tuple.1.len(), 12);
+    assert_eq!(results_tuple.2, [25.0].to_vec());
+}
+
+#[test]
+fn students_t_invalid_tail_probability_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false)
+        .with_detection_method(DetectionMethod::StudentT {
+            df: 3.0,
+            tail_prob: 0.5,
+        })
+        .get_outliers();
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::InvalidTailProbability)
+    ));
+}
+
+#[test]
+fn get_outliers_zscore_1() {
+    // The mean is 9.385 and the sample standard deviation is 15.18, so the fences are -20.97 and
+    // 39.74
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0, -40.0,
+    ]
+    .to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false)
+        .get_outliers_zscore(2.0)
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [-40.0].to_vec());
+    assert_eq!(results_tuple.1.len(), 12);
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn get_outliers_zscore_zero_variance() {
+    let data = [5.0, 5.0, 5.0, 5.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, true)
+        .get_outliers_zscore(3.0)
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(results_tuple.1, [5.0, 5.0, 5.0, 5.0].to_vec());
+    assert_eq!(results_tuple.2, [].to_vec());
+}
+
+#[test]
+fn min_gap_reclassifies_gradual_tail() {
+    let data = [
+        1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 13.0, 15.0, 17.0, 30.0,
+    ]
+    .to_vec();
+    let results_tuple = OutlierIdentifier::new(data, true)
+        .with_k_value(0.0)
+        .with_min_gap(5.0)
+        .get_outliers()
+        .unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 13.0, 15.0, 17.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [30.0].to_vec());
+}
+
+#[test]
+fn cross_validated_k_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let k_value = outlier_identifier.cross_validated_k(4, 7).unwrap();
+
+    assert!((0.5..=3.0).contains(&k_value));
+    assert_eq!(outlier_identifier.cross_validated_k(4, 7).unwrap(), k_value);
+}
+
+#[test]
+fn cross_validated_k_invalid_fold_count_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true);
+
+    assert!(matches!(
+        outlier_identifier.cross_validated_k(1, 0),
+        Err(OutlierError::InvalidFoldCount)
+    ));
+    assert!(matches!(
+        outlier_identifier.cross_validated_k(4, 0),
+        Err(OutlierError::InvalidFoldCount)
+    ));
+}
+
+#[test]
+fn headroom_1() {
+    let data = [9.0, 1.0, 8.0, 2.0, 7.0, 3.0, 6.0, 4.0, 5.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let interquartile_range = (7.0 + 1.0 / 3.0) - (2.0 + 2.0 / 3.0);
+    let upper_fence = 7.0 + 1.0 / 3.0 + 1.5 * interquartile_range;
+
+    let headroom = outlier_identifier.headroom(9.0).unwrap();
+    assert!((headroom - (upper_fence - 9.0) / interquartile_range).abs() < 1e-10);
+
+    let headroom = outlier_identifier
+        .headroom(upper_fence + interquartile_range)
+        .unwrap();
+    assert!((headroom + 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn output_precision_rounds_output_only() {
+    let data = [1.004, 2.006, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 100.123].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true).with_output_precision(2);
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [1.0, 2.01, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0].to_vec()
+    );
+    assert_eq!(results_tuple.2, [100.12].to_vec());
+
+    let multi_fences = OutlierIdentifier::new([0.0, 1.0, 2.0, 3.0].to_vec(), true)
+        .with_output_precision(1)
+        .multi_fences(&[0.0])
+        .unwrap();
+
+    assert_eq!(multi_fences[0].1.lower, 0.4);
+    assert_eq!(multi_fences[0].1.upper, 2.6);
+}
+
+#[test]
+fn robust_sigma_prepared_data_set() {
+    let data = [1.0, 2.0, f64::NAN, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 1000.0].to_vec();
+    let robust_sigma = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .with_exclude(|data| data > 100.0)
+        .with_quartile_method(QuartileMethod::Tukey)
+        .robust_sigma()
+        .unwrap();
+
+    assert!((robust_sigma - 4.0 / 1.349).abs() < 1e-10);
+}
+
+#[test]
+fn headroom_prepared_data_set() {
+    let data = [1.0, 2.0, f64::NAN, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 1000.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .with_exclude(|data| data > 100.0)
+        .with_quartile_method(QuartileMethod::Tukey);
+
+    assert_eq!(outlier_identifier.headroom(10.0), Ok(0.625));
+    assert_eq!(
+        outlier_identifier
+            .with_fixed_fences(0.0, 20.0)
+            .headroom(10.0),
+        Ok(2.5)
+    );
+}
+
+#[test]
+fn multi_fences_prepared_data_set() {
+    let data = [
+        10.0,
+        12.0,
+        f64::NAN,
+        11.0,
+        15.0,
+        11.0,
+        14.0,
+        13.0,
+        17.0,
+        12.0,
+        22.0,
+        14.0,
+        11.0,
+        1000.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .with_exclude(|data| data > 100.0)
+        .with_min_gap(1.0);
+
+    for k_value in [0.0, 1.5, 3.0] {
+        let (lower_fence, upper_fence) = outlier_identifier
+            .clone()
+            .with_k_value(k_value)
+            .fences()
+            .unwrap();
+        let multi_fences = outlier_identifier.multi_fences(&[k_value]).unwrap();
+
+        assert_eq!(multi_fences[0].1.lower, lower_fence);
+        assert_eq!(multi_fences[0].1.upper, upper_fence);
+    }
+
+    let multi_fences = OutlierIdentifier::new([5.0].to_vec(), false)
+        .multi_fences(&[1.5])
+        .unwrap();
+
+    assert_eq!(multi_fences[0].1.lower, f64::NEG_INFINITY);
+    assert_eq!(multi_fences[0].1.upper, f64::INFINITY);
+}
+
+#[test]
+fn labeled_error() {
+    let data: Vec<f64> = [1.0, f64::NAN].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_label("sensor_42");
+    let error = outlier_identifier.get_outliers().unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "sensor_42: The data set contains one or more NANs"
+    );
+    assert!(matches!(
+        error,
+        OutlierError::Labeled { error, .. } if matches!(*error, OutlierError::ContainsNans)
+    ));
+}
+
+#[test]
+fn partition_detailed_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let detailed_partition = OutlierIdentifier::new(data, false)
+        .partition_detailed()
+        .unwrap();
+
+    assert_eq!(detailed_partition.lower, [].to_vec());
+    assert_eq!(detailed_partition.below_lower_quartile, [10.0].to_vec());
+    assert_eq!(
+        detailed_partition.lower_quartile_to_median,
+        [11.0, 11.0, 11.0, 12.0, 12.0].to_vec()
+    );
+    assert_eq!(
+        detailed_partition.median_to_upper_quartile,
+        [13.0, 14.0, 14.0].to_vec()
+    );
+    assert_eq!(
+        detailed_partition.above_upper_quartile,
+        [15.0, 17.0].to_vec()
+    );
+    assert_eq!(detailed_partition.upper, [22.0].to_vec());
+}
+
+#[test]
+fn write_outliers_csv() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+    let mut output = Vec::new();
+    OutlierIdentifier::new(data, false)
+        .write_outliers(&mut output, OutputFormat::Csv)
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "value,side\n-62.3,lower\n43.3,non_outlier\n51.7,non_outlier\n65.43,non_outlier\n\
+         67.23,non_outlier\n67.9,non_outlier\n71.02,non_outlier\n"
+    );
+}
+
+#[test]
+fn write_csv_1() {
+    let data = [1.0, 2.0, 3.0, 4.0, 100.0].to_vec();
+    let mut output = Vec::new();
+    OutlierIdentifier::new(data, true)
+        .write_csv(&mut output)
+        .unwrap();
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "value,category\n1,non\n2,non\n3,non\n4,non\n100,upper\n"
+    );
+}
+
+#[test]
+fn write_csv_empty_data_set() {
+    let data: Vec<f64> = [].to_vec();
+    let mut output = Vec::new();
+    OutlierIdentifier::new(data, true)
+        .write_csv(&mut output)
+        .unwrap();
+
+    assert_eq!(String::from_utf8(output).unwrap(), "value,category\n");
+}
+
+#[test]
+fn write_outliers_jsonl() {
+    let data = [1.0, 2.0, 3.0, 4.0, 100.0].to_vec();
+    let mut output = Vec::new();
+    OutlierIdentifier::new(data, true)
+        .write_outliers(&mut output, OutputFormat::Jsonl)
+        .unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 5);
+    assert_eq!(lines[0], r#"{"value":1,"side":"non_outlier"}"#);
+    assert_eq!(lines[4], r#"{"value":100,"side":"upper"}"#);
+}
+
+#[test]
+fn classify_flat_1() {
+    let data = [67.9, -62.3, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+    let tagged_data_set = OutlierIdentifier::new(data, false).classify_flat().unwrap();
+
+    assert_eq!(
+        tagged_data_set,
+        [
+            (67.9, 1),
+            (-62.3, 0),
+            (71.02, 1),
+            (43.3, 1),
+            (51.7, 1),
+            (65.43, 1),
+            (67.23, 1)
+        ]
+        .to_vec()
+    );
+}
+
+#[test]
+fn classify_flat_removed_values() {
+    let data = [
+        67.9,
+        f64::NAN,
+        -62.3,
+        -999.0,
+        71.02,
+        43.3,
+        51.7,
+        65.43,
+        67.23,
+    ]
+    .to_vec();
+    let tagged_data_set = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .with_exclude(|data| data == -999.0)
+        .classify_flat()
+        .unwrap();
+
+    assert_eq!(
+        tagged_data_set,
+        [
+            (67.9, 1),
+            (-62.3, 0),
+            (71.02, 1),
+            (43.3, 1),
+            (51.7, 1),
+            (65.43, 1),
+            (67.23, 1)
+        ]
+        .to_vec()
+    );
+}
+
+#[test]
+fn winsorize_1() {
+    let data = [0.0, 3.0, 2.0, 1.0, -10.0, 10.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_k_value(1.0);
+    let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
+    let winsorized_data_set = outlier_identifier.winsorize().unwrap();
+
+    assert_eq!(
+        winsorized_data_set,
+        [0.0, 3.0, 2.0, 1.0, lower_fence, upper_fence].to_vec()
+    );
+}
+
+#[test]
+fn winsorize_removed_values() {
+    let data = [0.0, -999.0, 3.0, 2.0, f64::NAN, 1.0, -10.0, 10.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false)
+        .with_k_value(1.0)
+        .with_skip_nans(true)
+        .with_exclude(|data| data == -999.0);
+    let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
+    let winsorized_data_set = outlier_identifier.winsorize().unwrap();
+
+    assert_eq!(
+        winsorized_data_set,
+        [0.0, 3.0, 2.0, 1.0, lower_fence, upper_fence].to_vec()
+    );
+}
+
+#[test]
+fn soft_classify_1() {
+    let data = [4.0, 2.0, 3.0, 1.0, 0.0].to_vec();
+    let probabilities = OutlierIdentifier::new(data, false)
+        .with_k_value(0.0)
+        .soft_classify(10.0)
+        .unwrap();
+    let (lower_quartile, upper_quartile) = (2.0 / 3.0, 3.0 + 1.0 / 3.0);
+    let fence_width = upper_quartile - lower_quartile;
+    let logistic = |distance: f64| 1.0 / (1.0 + (-10.0 * distance / fence_width).exp());
+
+    assert!((probabilities[0] - logistic(4.0 - upper_quartile)).abs() < 1e-10);
+    assert!(
+        (probabilities[2] - logistic(-(3.0 - lower_quartile).min(upper_quartile - 3.0))).abs()
+            < 1e-10
+    );
+    assert!(probabilities[0] > 0.5);
+    assert!(probabilities[1] < 0.5);
+    assert!(probabilities[4] > 0.5);
+}
+
+#[test]
+fn soft_classify_measurement_errors() {
+    let values = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 25.0, 14.0, 11.0, 24.0,
+    ]
+    .to_vec();
+    let errors = [
+        0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1, 5.0,
+    ]
+    .to_vec();
+    let probabilities = OutlierIdentifier::new_with_errors(values, errors, 1.5)
+        .soft_classify(10.0)
+        .unwrap();
+
+    assert!(probabilities[9] > 0.5);
+    assert!(probabilities[12] < 0.5);
+}
+
+#[test]
+fn soft_classify_zero_width_fences() {
+    let data = [5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 9.0].to_vec();
+    let probabilities = OutlierIdentifier::new(data, false)
+        .soft_classify(10.0)
+        .unwrap();
+
+    assert_eq!(probabilities[..8], [0.0; 8]);
+    assert_eq!(probabilities[8], 1.0);
+}
+
+#[test]
+fn soft_classify_small_data_set() {
+    let data = [5.0, f64::NAN].to_vec();
+    let probabilities = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .soft_classify(10.0)
+        .unwrap();
+
+    assert_eq!(probabilities, [0.0].to_vec());
+}
+
+#[test]
+fn hierarchical_outliers_1() {
+    let data = [
+        1.0, 2.0, 3.0, 2.5, 1.5, 2.2, 9.0, 101.0, 102.0, 100.0, 103.0, 101.5, 102.5, 60.0,
+    ]
+    .to_vec();
+    let outlier_result = OutlierIdentifier::new(data.clone(), false)
+        .hierarchical_outliers(1, 1.5)
+        .unwrap();
+
+    assert_eq!(outlier_result.lower, [60.0].to_vec());
+    assert_eq!(
+        outlier_result.non_outliers,
+        [1.0, 1.5, 2.0, 2.2, 2.5, 3.0, 100.0, 101.0, 101.5, 102.0, 102.5, 103.0].to_vec()
+    );
+    assert_eq!(outlier_result.upper, [9.0].to_vec());
+
+    // Without splitting, the global fences are too wide to flag anything
+    let outlier_result = OutlierIdentifier::new(data, false)
+        .hierarchical_outliers(0, 1.5)
+        .unwrap();
+
+    assert_eq!(outlier_result.lower.len() + outlier_result.upper.len(), 0);
+}
+
+#[test]
+fn hierarchical_outliers_infinities() {
+    let data = [
+        1.0,
+        2.0,
+        3.0,
+        2.5,
+        1.5,
+        2.2,
+        9.0,
+        101.0,
+        102.0,
+        100.0,
+        103.0,
+        101.5,
+        102.5,
+        60.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ]
+    .to_vec();
+    let outlier_result = OutlierIdentifier::new(data, false)
+        .with_infinities_as_outliers(true)
+        .hierarchical_outliers(1, 1.5)
+        .unwrap();
+
+    assert_eq!(outlier_result.lower, [f64::NEG_INFINITY, 60.0].to_vec());
+    assert_eq!(
+        outlier_result.non_outliers,
+        [1.0, 1.5, 2.0, 2.2, 2.5, 3.0, 100.0, 101.0, 101.5, 102.0, 102.5, 103.0].to_vec()
+    );
+    assert_eq!(outlier_result.upper, [9.0, f64::INFINITY].to_vec());
+}
+
+#[test]
+fn metrics_1() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+    let metrics = OutlierIdentifier::new(data, false).metrics().unwrap();
+
+    assert_eq!(metrics.len(), 7);
+    assert_eq!(metrics["outlier_count"], 1.0);
+    assert_eq!(metrics["lower_outlier_count"], 1.0);
+    assert_eq!(metrics["upper_outlier_count"], 0.0);
+    assert!(metrics["lower_fence"] > -62.3 && metrics["lower_fence"] < 43.3);
+    assert!(metrics["upper_fence"] > 71.02);
+    assert_eq!(metrics["outlier_fraction"], 1.0 / 7.0);
+    assert_eq!(metrics["median"], 65.43);
+}
+
+#[test]
+fn metrics_empty_data_set() {
+    let metrics = OutlierIdentifier::new([].to_vec(), true).metrics().unwrap();
+
+    assert_eq!(metrics["outlier_count"], 0.0);
+    assert_eq!(metrics["outlier_fraction"], 0.0);
+}
+
+#[test]
+fn iterative_removal_trace_1() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 20.0, 60.0].to_vec();
+    let removal_steps = OutlierIdentifier::new(data, false)
+        .iterative_removal_trace()
+        .unwrap();
+
+    assert_eq!(removal_steps.len(), 2);
+    assert_eq!(removal_steps[0].removed_value, 60.0);
+    assert_eq!(removal_steps[0].remaining_count, 9);
+    assert_eq!(removal_steps[1].removed_value, 20.0);
+    assert_eq!(removal_steps[1].remaining_count, 8);
+    assert!(removal_steps[1].fences.upper < removal_steps[0].fences.upper);
+    assert!(removal_steps[1].fences.upper > 8.0);
+}
+
+#[test]
+fn mixture_outliers_1() {
+    let data = [
+        10.2, 9.8, 10.1, 9.9, 10.0, 10.3, 9.7, 10.1, 9.9, 10.0, 10.2, 9.8, 10.4, 9.6, 10.0, 10.1,
+        14.9, 15.1, 15.0,
+    ]
+    .to_vec();
+    let mixture_outliers = OutlierIdentifier::new(data, false)
+        .mixture_outliers(2, 0.25)
+        .unwrap();
+
+    assert_eq!(mixture_outliers, [14.9, 15.0, 15.1].to_vec());
+}
+
+#[test]
+fn mixture_outliers_invalid_component_count_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let mixture_outliers = OutlierIdentifier::new(data, true).mixture_outliers(0, 0.25);
+
+    assert!(matches!(
+        mixture_outliers,
+        Err(OutlierError::InvalidComponentCount)
+    ));
+}
+
+#[test]
+fn has_outliers_false() {
+    let data: Vec<f64> = [1.0, 2.0, 4.0, 10.0].to_vec();
+    let has_outliers = OutlierIdentifier::new(data, true).has_outliers().unwrap();
+
+    assert!(!has_outliers);
+}
+
+#[test]
+fn has_outliers_true() {
+    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+    let has_outliers = OutlierIdentifier::new(data, false).has_outliers().unwrap();
+
+    assert!(has_outliers);
+}
+
+#[test]
+fn has_upper_outliers_and_has_lower_outliers_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert!(outlier_identifier.clone().has_upper_outliers().unwrap());
+    assert!(!outlier_identifier.has_lower_outliers().unwrap());
+}
+
+#[test]
+fn impact_summary_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let impact_summary = OutlierIdentifier::new(data, false)
+        .impact_summary()
+        .unwrap();
+
+    assert!((impact_summary.raw.mean - 13.5).abs() < 1e-10);
+    assert_eq!(impact_summary.raw.median, 12.5);
+    assert!((impact_summary.cleaned.mean - 140.0 / 11.0).abs() < 1e-10);
+    assert_eq!(impact_summary.cleaned.median, 12.0);
+    assert!(impact_summary.cleaned.std_dev < impact_summary.raw.std_dev);
+}
+
+#[test]
+fn impact_summary_nan_error() {
+    let data: Vec<f64> = [1.0, f64::NAN].to_vec();
+    let impact_summary = OutlierIdentifier::new(data, false).impact_summary();
+
+    assert!(matches!(impact_summary, Err(OutlierError::ContainsNans)));
+}
+
+#[test]
+fn sorted_data_set_log_transform() {
+    let data = [
+        120.0, 95.0, 130.0, 110.0, 2500.0, 105.0, 140.0, 98.0, 115.0, 5.0, 125.0,
+    ]
+    .to_vec();
+    let log_data: Vec<f64> = data.iter().map(|data: &f64| data.ln()).collect();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_log_transform(true);
+    let log_identifier = OutlierIdentifier::new(log_data, false);
+    let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
+    let multi_fences = outlier_identifier.multi_fences(&[1.5]).unwrap();
+
+    assert!((multi_fences[0].1.lower - lower_fence).abs() < 1e-9);
+    assert!((multi_fences[0].1.upper - upper_fence).abs() < 1e-9);
+    assert_eq!(
+        outlier_identifier.robust_sigma(),
+        log_identifier.robust_sigma()
+    );
+    assert_eq!(
+        outlier_identifier.headroom(200.0),
+        log_identifier.headroom(200.0_f64.ln())
+    );
+    assert_eq!(
+        outlier_identifier.headroom(0.0),
+        Err(OutlierError::ContainsNonPositiveValues)
+    );
+}
+
+#[test]
+fn sorted_data_set_infinities() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let finite_identifier = OutlierIdentifier::new(data.clone(), false);
+    let outlier_identifier = OutlierIdentifier::new(
+        [data, [f64::INFINITY, f64::NEG_INFINITY].to_vec()].concat(),
+        false,
+    );
+
+    assert_eq!(
+        outlier_identifier.robust_sigma(),
+        Err(OutlierError::ContainsInfinities)
+    );
+    assert_eq!(
+        outlier_identifier.grubbs_test(0.05),
+        Err(OutlierError::ContainsInfinities)
+    );
+    assert_eq!(
+        outlier_identifier.clone().get_outliers_mad(3.5),
+        Err(OutlierError::ContainsInfinities)
+    );
+
+    let outlier_identifier = outlier_identifier.with_infinities_as_outliers(true);
+
+    assert_eq!(
+        outlier_identifier.robust_sigma(),
+        finite_identifier.robust_sigma()
+    );
+    assert_eq!(
+        outlier_identifier.multi_fences(&[1.5, 3.0]),
+        finite_identifier.multi_fences(&[1.5, 3.0])
+    );
+    assert_eq!(
+        outlier_identifier.headroom(20.0),
+        finite_identifier.headroom(20.0)
+    );
+    assert_eq!(
+        outlier_identifier.tail_index(),
+        finite_identifier.tail_index()
+    );
+    assert_eq!(
+        outlier_identifier.cross_validated_k(4, 7),
+        finite_identifier.cross_validated_k(4, 7)
+    );
+    assert_eq!(
+        outlier_identifier.grubbs_test(0.05),
+        Ok(Some(f64::INFINITY))
+    );
+    assert_eq!(
+        outlier_identifier.dixon_q_test(0.05),
+        Ok(Some(f64::INFINITY))
+    );
+
+    let (lower, _, upper) = outlier_identifier.clone().get_outliers_mad(3.5).unwrap();
+
+    assert_eq!(lower, [f64::NEG_INFINITY].to_vec());
+    assert_eq!(upper, [22.0, f64::INFINITY].to_vec());
+
+    let outliers = outlier_identifier.mixture_outliers(2, 0.2).unwrap();
+
+    assert_eq!(outliers.first(), Some(&f64::NEG_INFINITY));
+    assert_eq!(outliers.last(), Some(&f64::INFINITY));
+}
+
+#[test]
+fn impact_summary_skip_nans() {
+    let data = [
+        10.0,
+        12.0,
+        11.0,
+        15.0,
+        f64::NAN,
+        11.0,
+        14.0,
+        13.0,
+        17.0,
+        12.0,
+        22.0,
+        14.0,
+        11.0,
+        f64::INFINITY,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_skip_nans(true);
+
+    assert!(matches!(
+        outlier_identifier.clone().impact_summary(),
+        Err(OutlierError::ContainsInfinities)
+    ));
+
+    let impact_summary = outlier_identifier
+        .with_infinities_as_outliers(true)
+        .impact_summary()
+        .unwrap();
+
+    assert!((impact_summary.raw.mean - 13.5).abs() < 1e-10);
+    assert_eq!(impact_summary.raw.median, 12.5);
+    assert!((impact_summary.cleaned.mean - 140.0 / 11.0).abs() < 1e-10);
+    assert_eq!(impact_summary.cleaned.median, 12.0);
+}
+
+#[test]
+fn with_quartile_method_1() {
+    let quartiles = |data: &[f64], quartile_method| {
+        OutlierIdentifier::new(data.to_vec(), true)
+            .with_k_value(0.0)
+            .with_quartile_method(quartile_method)
+            .fences()
+            .unwrap()
+    };
+    let even_data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    let odd_data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0];
+
+    assert_eq!(quartiles(&even_data, QuartileMethod::Tukey), (2.5, 6.5));
+    assert_eq!(quartiles(&odd_data, QuartileMethod::Tukey), (3.0, 7.0));
+    assert_eq!(quartiles(&even_data, QuartileMethod::Linear), (2.75, 6.25));
+    assert_eq!(quartiles(&even_data, QuartileMethod::Midpoint), (2.5, 6.5));
+    assert_eq!(
+        quartiles(&even_data, QuartileMethod::Exclusive),
+        (2.25, 6.75)
+    );
+    assert_eq!(
+        quartiles(&even_data, QuartileMethod::MedianUnbiased),
+        quartiles(&even_data, QuartileMethod::default())
+    );
+}
+
+#[test]
+fn get_fences_default_quartiles_even_and_odd_lengths() {
+    // R's type 8 quartiles, computed by hand: for the even length set the positions are 2 5/12 and
+    // 6 7/12, and for the odd length set they are 2 2/3 and 7 1/3
+    let even_data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].to_vec();
+    let odd_data = [9.0, 1.0, 8.0, 2.0, 7.0, 3.0, 6.0, 4.0, 5.0].to_vec();
+    let (even_lower_quartile, even_upper_quartile) = OutlierIdentifier::new(even_data, true)
+        .with_k_value(0.0)
+        .fences()
+        .unwrap();
+    let (odd_lower_quartile, odd_upper_quartile) = OutlierIdentifier::new(odd_data, false)
+        .with_k_value(0.0)
+        .fences()
+        .unwrap();
+
+    assert!((even_lower_quartile - 29.0 / 12.0).abs() < 1e-10);
+    assert!((even_upper_quartile - 79.0 / 12.0).abs() < 1e-10);
+    assert!((odd_lower_quartile - 8.0 / 3.0).abs() < 1e-10);
+    assert!((odd_upper_quartile - 22.0 / 3.0).abs() < 1e-10);
+}
+
+#[test]
+fn summary_1() {
+    let data = [20.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].to_vec();
+    let summary = OutlierIdentifier::new(data, false)
+        .with_quartile_method(QuartileMethod::Tukey)
+        .summary()
+        .unwrap();
+
+    assert_eq!(
+        summary,
+        Summary {
+            q1: 3.0,
+            median: 5.0,
+            q3: 7.0,
+            iqr: 4.0,
+            lower_fence: -3.0,
+            upper_fence: 13.0,
+            min: 1.0,
+            max: 20.0,
+        }
+    );
+}
+
+#[test]
+fn get_outliers_infinities_error() {
+    for infinity in [f64::INFINITY, f64::NEG_INFINITY] {
+        let data = [1.0, 2.0, infinity, 3.0].to_vec();
+        let results_tuple = OutlierIdentifier::new(data, false).get_outliers();
+
+        assert!(matches!(
+            results_tuple,
+            Err(OutlierError::ContainsInfinities)
+        ));
+    }
+}
+
+#[test]
+fn get_outliers_infinities_as_outliers() {
+    let finite_data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ];
+    let mut data = finite_data.to_vec();
+    data.extend([f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY]);
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_infinities_as_outliers(true);
+
+    assert_eq!(
+        outlier_identifier.fences().unwrap(),
+        OutlierIdentifier::new(finite_data.to_vec(), false)
+            .fences()
+            .unwrap()
+    );
+
+    let results_tuple = outlier_identifier.get_outliers().unwrap();
+
+    assert_eq!(results_tuple.0, [f64::NEG_INFINITY].to_vec());
+    assert_eq!(
+        results_tuple.1,
+        [10.0, 11.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 14.0, 15.0, 17.0].to_vec()
+    );
+    assert_eq!(
+        results_tuple.2,
+        [22.0, f64::INFINITY, f64::INFINITY].to_vec()
+    );
+}
+
+#[test]
+fn with_strategy_1() {
+    // Flags the values more than `width` from the median
+    struct MedianWidth(f64);
+
+    impl OutlierStrategy for MedianWidth {
+        fn fences(&self, sorted_data: &[f64]) -> Result<(f64, f64), OutlierError> {
+            let median = quantile(sorted_data, 0.5);
+            Ok((median - self.0, median + self.0))
+        }
+    }
+
+    let data = [
+        f64::NAN,
+        10.0,
+        12.0,
+        11.0,
+        15.0,
+        11.0,
+        14.0,
+        13.0,
+        17.0,
+        12.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .with_k_value(-1.0)
+        .with_strategy(MedianWidth(3.0));
+
+    assert_eq!(outlier_identifier.fences().unwrap(), (9.0, 15.0));
+    assert_eq!(
+        outlier_identifier.get_outliers().unwrap(),
+        (
+            [].to_vec(),
+            [10.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 15.0].to_vec(),
+            [17.0].to_vec()
+        )
+    );
+}
+
+#[test]
+fn with_strategy_errors() {
+    struct Failing;
+
+    impl OutlierStrategy for Failing {
+        fn fences(&self, _: &[f64]) -> Result<(f64, f64), OutlierError> {
+            Err(OutlierError::SingularCovariance)
         }
     }
 
-    /// Allows for altering the `k_value`.  A larger `k_value` will result in fewer numbers being
-    /// identified as outliers, while a smaller `k_value` will result in more numbers being
-    /// identified as outliers.  The `k_value` must be non-negative, or `get_outliers()` will return
-    /// an `Err`.
-    pub fn with_k_value(self, k_value: f64) -> OutlierIdentifier {
-        OutlierIdentifier { k_value, ..self }
+    struct Inverted;
+
+    impl OutlierStrategy for Inverted {
+        fn fences(&self, _: &[f64]) -> Result<(f64, f64), OutlierError> {
+            Ok((1.0, 0.0))
+        }
     }
 
-    /// Performs the outlier identification.  In the case that is does not return an `Err`, it
-    /// returns a tuple of `Vec<f64>`s.  The first vector contains any lower outliers and the third
-    /// vector contains any upper outliers.  Additionally, the second vector returned contains all
-    /// the non-outliers, so that the data set passed in is returned, in its entirety, as
-    /// partitioned subsets.  `get_outliers()` will return an `Err` if the `data_set` contains one
-    /// or more `NAN`s or if the `k_value` is a negative number.
-    #[allow(clippy::type_complexity)]
-    pub fn get_outliers(mut self) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), OutlierError> {
-        let (lower_fence, upper_fence) = self.get_fences()?;
+    let data = [1.0, 2.0, 3.0].to_vec();
 
-        let mut lower_outliers: Vec<f64> = Vec::new();
-        let mut upper_outliers: Vec<f64> = Vec::new();
-        let mut non_outliers: Vec<f64> = Vec::new();
+    assert_eq!(
+        OutlierIdentifier::new(data.clone(), true)
+            .with_strategy(Failing)
+            .get_outliers(),
+        Err(OutlierError::SingularCovariance)
+    );
+    assert_eq!(
+        OutlierIdentifier::new(data, true)
+            .with_strategy(Inverted)
+            .get_outliers(),
+        Err(OutlierError::InvalidFences)
+    );
+}
 
-        for data in self.data_set {
-            if data < lower_fence {
-                lower_outliers.push(data);
-            } else if data > upper_fence {
-                upper_outliers.push(data);
-            } else {
-                non_outliers.push(data);
-            }
-        }
+#[test]
+fn get_outliers_with_fences_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+    let (results_tuple, fences) = outlier_identifier
+        .clone()
+        .get_outliers_with_fences()
+        .unwrap();
 
-        Ok((lower_outliers, non_outliers, upper_outliers))
-    }
+    assert_eq!(
+        results_tuple,
+        outlier_identifier.get_outliers_ref().unwrap()
+    );
+    assert_eq!(fences, outlier_identifier.fences().unwrap());
+}
 
-    /// Indicates whether the data set has outliers.  This method is useful when one only needs to
-    /// know if a data set contains outliers, but isn't concerned with their details.  This method
-    /// short circuits; if any outliers exist, the moment the first one is found, the method
-    /// immediately returns with `true`, else, it returns `false`.
-    pub fn has_outliers(mut self) -> Result<bool, OutlierError> {
-        let (lower_fence, upper_fence) = self.get_fences()?;
+#[test]
+fn with_fixed_fences_1() {
+    let baseline = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let (lower_fence, upper_fence) = OutlierIdentifier::new(baseline, false).fences().unwrap();
 
-        for data in self.data_set {
-            if data < lower_fence || data > upper_fence {
-                return Ok(true);
-            }
-        }
+    // Computed from the batch itself, the fences would flag nothing
+    let batch = [30.0, 2.0, 31.0, 29.0, 12.0, 32.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(batch, false)
+        .with_fixed_fences(lower_fence, upper_fence)
+        .get_outliers()
+        .unwrap();
 
-        Ok(false)
-    }
+    assert_eq!(results_tuple.0, [2.0].to_vec());
+    assert_eq!(results_tuple.1, [12.0].to_vec());
+    assert_eq!(results_tuple.2, [29.0, 30.0, 31.0, 32.0].to_vec());
+}
 
-    fn get_fences(&mut self) -> Result<(f64, f64), OutlierError> {
-        if self.k_value < 0.0 {
-            return Err(OutlierError::NegativeKValue);
+#[test]
+fn learn_fences_and_classify_batch() {
+    let training_data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(training_data, false);
+    let fences = outlier_identifier.learn_fences().unwrap();
+    let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
+
+    assert_eq!(
+        fences,
+        Fences {
+            lower: lower_fence,
+            upper: upper_fence
         }
+    );
+    assert_eq!(
+        fences.classify_batch(&[30.0, 12.0, 0.0, 13.0, 25.0]),
+        ([0.0].to_vec(), [12.0, 13.0].to_vec(), [30.0, 25.0].to_vec())
+    );
+}
 
-        // This should catch cases where the next `unwrap()` would panic, see:
-        // https://doc.rust-lang.org/std/vec/struct.Vec.html#method.sort_by
-        let data_set_has_nans = self.data_set.iter().any(|x| x.is_nan());
+#[test]
+fn with_fixed_fences_invalid_fences_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
 
-        if data_set_has_nans {
-            return Err(OutlierError::ContainsNans);
-        }
+    assert!(matches!(
+        outlier_identifier
+            .clone()
+            .with_fixed_fences(2.0, 1.0)
+            .get_outliers(),
+        Err(OutlierError::InvalidFences)
+    ));
+    assert!(matches!(
+        outlier_identifier
+            .with_fixed_fences(f64::NAN, 1.0)
+            .get_outliers(),
+        Err(OutlierError::InvalidFences)
+    ));
+}
 
-        if !self.data_is_sorted {
-            self.data_set.sort_by(|a, b| a.partial_cmp(b).unwrap());
-            self.data_is_sorted = true;
-        }
+#[test]
+fn with_inclusive_fences_1() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, true).with_fixed_fences(2.0, 7.0);
+    let exclusive_results_tuple = outlier_identifier.clone().get_outliers().unwrap();
+    let inclusive_outlier_identifier = outlier_identifier.with_inclusive_fences(true);
 
-        let lower_quartile = self.data_set.lower_quartile();
-        let upper_quartile = self.data_set.upper_quartile();
-        let interquartile_range = upper_quartile - lower_quartile;
+    assert_eq!(exclusive_results_tuple.0, [1.0].to_vec());
+    assert_eq!(exclusive_results_tuple.2, [8.0].to_vec());
+    assert_eq!(
+        inclusive_outlier_identifier.classify(7.0).unwrap(),
+        Classification::UpperOutlier
+    );
+    assert_eq!(
+        inclusive_outlier_identifier.get_outliers().unwrap(),
+        (
+            [1.0, 2.0].to_vec(),
+            [3.0, 4.0, 5.0, 6.0].to_vec(),
+            [7.0, 8.0].to_vec()
+        )
+    );
+}
 
-        let quartile_adjustment_value = self.k_value * interquartile_range;
-        let lower_fence = lower_quartile - quartile_adjustment_value;
-        let upper_fence = upper_quartile + quartile_adjustment_value;
+#[test]
+fn validate_1() {
+    let data = [1.0, f64::NAN, 3.0, f64::INFINITY, f64::NAN].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false)
+        .with_k_value(-1.0)
+        .with_min_size(4);
 
-        Ok((lower_fence, upper_fence))
-    }
+    assert_eq!(
+        outlier_identifier.validate(),
+        Err([
+            OutlierError::NegativeKValue,
+            OutlierError::ContainsNansAt([1, 4].to_vec()),
+            OutlierError::ContainsInfinities,
+            OutlierError::InsufficientData {
+                required: 4,
+                found: 3
+            },
+        ]
+        .to_vec())
+    );
+    assert!(outlier_identifier.get_outliers().is_err());
 }
 
 #[test]
-fn get_outliers_not_sorted_nan_error() {
-    let data: Vec<f64> = [f64::NAN, f64::NAN].to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, false);
-    let results_tuple = outlier_identifier.get_outliers();
+fn validate_no_problems() {
+    let data = [1.0, f64::NAN, 3.0, 4.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false)
+        .with_skip_nans(true)
+        .with_min_size(3);
 
-    assert!(matches!(results_tuple, Err(OutlierError::ContainsNans)));
+    assert_eq!(outlier_identifier.validate(), Ok(()));
+    assert!(outlier_identifier.get_outliers().is_ok());
 }
 
 #[test]
-fn get_outliers_sorted_nan_error() {
-    let data: Vec<f64> = [3.0, 2.9, 2.8, 33.3, f64::NAN, f64::NAN].to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, true);
-    let results_tuple = outlier_identifier.get_outliers();
+fn classify_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
 
-    assert!(matches!(results_tuple, Err(OutlierError::ContainsNans)));
+    assert_eq!(
+        outlier_identifier.classify(2.0).unwrap(),
+        Classification::LowerOutlier
+    );
+    assert_eq!(
+        outlier_identifier.classify(12.5).unwrap(),
+        Classification::NonOutlier
+    );
+    assert_eq!(
+        outlier_identifier.classify(22.0).unwrap(),
+        Classification::UpperOutlier
+    );
 }
 
 #[test]
-fn get_outliers_empty_data_set() {
-    let data: Vec<f64> = [].to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, true);
-    let results_tuple = outlier_identifier.get_outliers().unwrap();
+fn with_preserve_order_1() {
+    let data = [
+        14.0, 10.0, 22.0, 12.0, 11.0, 15.0, 11.0, 17.0, 13.0, 12.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_preserve_order(true);
 
-    assert_eq!(results_tuple.0, [].to_vec());
-    assert_eq!(results_tuple.1, [].to_vec());
-    assert_eq!(results_tuple.2, [].to_vec());
+    assert_eq!(
+        outlier_identifier.clone().get_outliers().unwrap(),
+        (
+            [].to_vec(),
+            [14.0, 10.0, 12.0, 11.0, 15.0, 11.0, 17.0, 13.0, 12.0, 14.0, 11.0].to_vec(),
+            [22.0].to_vec()
+        )
+    );
+    assert_eq!(
+        outlier_identifier.get_outlier_indices().unwrap().1,
+        [0, 1, 3, 4, 5, 6, 7, 8, 9, 10, 11].to_vec()
+    );
 }
 
 #[test]
-fn get_outliers_set_of_one() {
-    let data: Vec<f64> = [30.0].to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, true);
-    let results_tuple = outlier_identifier.get_outliers().unwrap();
+fn count_outliers_1() {
+    let data = [
+        -40.0, 10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
 
-    assert_eq!(results_tuple.0, [].to_vec());
-    assert_eq!(results_tuple.1, [30.0].to_vec());
-    assert_eq!(results_tuple.2, [].to_vec());
+    assert_eq!(outlier_identifier.count_outliers().unwrap(), (1, 1));
 }
 
 #[test]
-fn get_outliers_set_of_two() {
-    let data: Vec<f64> = [30.0, 90.0].to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, true);
-    let results_tuple = outlier_identifier.get_outliers().unwrap();
+fn get_outliers_tiered_1() {
+    // The quartiles are 10 2/3 and 14 1/3, so the inner fences are 5 1/6 and 19 5/6, and the outer
+    // fences are -1/3 and 25 1/3
+    let data = [
+        -5.0, 3.0, 10.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 14.0, 15.0, 22.0, 40.0,
+    ]
+    .to_vec();
+    let tiered_partition = OutlierIdentifier::new(data, false)
+        .get_outliers_tiered()
+        .unwrap();
 
-    assert_eq!(results_tuple.0, [].to_vec());
-    assert_eq!(results_tuple.1, [30.0, 90.0].to_vec());
-    assert_eq!(results_tuple.2, [].to_vec());
+    assert_eq!(
+        tiered_partition,
+        TieredPartition {
+            extreme_lower: [-5.0].to_vec(),
+            mild_lower: [3.0].to_vec(),
+            non_outliers: [10.0, 11.0, 11.0, 12.0, 12.0, 13.0, 14.0, 14.0, 15.0].to_vec(),
+            mild_upper: [22.0].to_vec(),
+            extreme_upper: [40.0].to_vec(),
+        }
+    );
 }
 
 #[test]
-fn get_outliers_none() {
-    let data: Vec<f64> = [1.0, 2.0, 4.0, 10.0].to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, true);
-    let results_tuple = outlier_identifier.get_outliers().unwrap();
+fn from_slice_1() {
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ];
 
-    assert_eq!(results_tuple.0, [].to_vec());
-    assert_eq!(results_tuple.1, [1.0, 2.0, 4.0, 10.0].to_vec());
-    assert_eq!(results_tuple.2, [].to_vec());
+    assert_eq!(
+        OutlierIdentifier::from_slice(&data, false)
+            .get_outliers()
+            .unwrap(),
+        OutlierIdentifier::new(data.to_vec(), false)
+            .get_outliers()
+            .unwrap()
+    );
 }
 
 #[test]
-fn get_outliers_1() {
+fn upper_outliers_and_lower_outliers_1() {
     let data = [
-        0.0, 3.0, 3.0, 3.0, 11.0, 12.0, 13.0, 15.0, 19.0, 20.0, 29.0, 40.0, 79.0,
+        -40.0, 10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
     ]
     .to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, true);
-    let results_tuple = outlier_identifier.get_outliers().unwrap();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
 
-    assert_eq!(results_tuple.0, [].to_vec());
     assert_eq!(
-        results_tuple.1,
-        [0.0, 3.0, 3.0, 3.0, 11.0, 12.0, 13.0, 15.0, 19.0, 20.0, 29.0, 40.0].to_vec()
+        outlier_identifier.clone().upper_outliers().unwrap(),
+        [22.0].to_vec()
+    );
+    assert_eq!(
+        outlier_identifier.lower_outliers().unwrap(),
+        [-40.0].to_vec()
     );
-    assert_eq!(results_tuple.2, [79.0].to_vec());
 }
 
 #[test]
-fn get_outliers_negative_1() {
+fn with_log_transform_1() {
+    // Roughly log-normal data, whose long upper tail is flagged on the raw scale
+    let data = [1.0, 2.0, 3.0, 4.0, 6.0, 8.0, 12.0, 16.0, 24.0, 32.0, 64.0].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(
+        outlier_identifier.clone().get_outliers().unwrap().2,
+        [64.0].to_vec()
+    );
+
+    let outlier_identifier = outlier_identifier.with_log_transform(true);
+    let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
+
+    assert!(lower_fence > 0.0);
+    assert!(upper_fence > 64.0);
+    assert_eq!(outlier_identifier.count_outliers().unwrap(), (0, 0));
+}
+
+#[test]
+fn with_log_transform_non_positive_error() {
+    let data = [1.0, 2.0, 0.0, 4.0].to_vec();
+    let results_tuple = OutlierIdentifier::new(data, false)
+        .with_log_transform(true)
+        .get_outliers();
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::ContainsNonPositiveValues)
+    ));
+}
+
+#[test]
+fn outlier_fraction_1() {
     let data = [
-        29.5, -3.79, 15.0, 11.47, 3.6, 3.6, 19.0, 79.37, 40.7, -23.3, 12.0, 20.113, 13.39,
+        -40.0, 10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
     ]
     .to_vec();
+
+    assert_eq!(
+        OutlierIdentifier::new(data, false)
+            .outlier_fraction()
+            .unwrap(),
+        2.0 / 13.0
+    );
+    assert_eq!(
+        OutlierIdentifier::<f64>::new([].to_vec(), false)
+            .outlier_fraction()
+            .unwrap(),
+        0.0
+    );
+}
+
+#[test]
+fn get_outliers_with_distance_1() {
+    let data = [0.0, 1.0, 2.0, 3.0, 30.0, -20.0].to_vec();
     let outlier_identifier = OutlierIdentifier::new(data, false);
-    let results_tuple = outlier_identifier.get_outliers().unwrap();
+    let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
 
-    assert_eq!(results_tuple.0, [].to_vec());
     assert_eq!(
-        results_tuple.1,
-        [-23.3, -3.79, 3.6, 3.6, 11.47, 12.0, 13.39, 15.0, 19.0, 20.113, 29.5, 40.7].to_vec()
+        outlier_identifier.get_outliers_with_distance().unwrap(),
+        [(-20.0, lower_fence - -20.0), (30.0, 30.0 - upper_fence)].to_vec()
     );
-    assert_eq!(results_tuple.2, [79.37].to_vec());
 }
 
 #[test]
-fn get_outliers_negative_2() {
-    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
+fn most_extreme_outlier_1() {
+    let data = [0.0, 1.0, 2.0, 3.0, 30.0, -20.0].to_vec();
+
+    assert_eq!(
+        OutlierIdentifier::new(data, false)
+            .most_extreme_outlier()
+            .unwrap(),
+        Some(30.0)
+    );
+}
+
+#[test]
+fn most_extreme_outlier_none() {
+    let data = [1.0, 2.0, 3.0, 4.0].to_vec();
+
+    assert_eq!(
+        OutlierIdentifier::new(data, true)
+            .most_extreme_outlier()
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn outlier_error_partial_eq() {
+    let data = [1.0, f64::NAN].to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false).with_label("latency");
+
+    assert_eq!(
+        outlier_identifier.get_outliers(),
+        Err(OutlierError::Labeled {
+            label: "latency".to_string(),
+            error: Box::new(OutlierError::ContainsNans),
+        })
+    );
+}
+
+#[test]
+fn get_outliers_detrended_1() {
+    // A noisy upward trend with a single spike at index 10
+    let noise = [0.0, 1.5, -1.0, 0.5, -1.5, 1.0];
+    let mut data: Vec<f64> = (0..20)
+        .map(|i| 10.0 + 2.0 * i as f64 + noise[i % noise.len()])
+        .collect();
+    data[10] += 15.0;
     let outlier_identifier = OutlierIdentifier::new(data, false);
-    let results_tuple = outlier_identifier.get_outliers().unwrap();
 
-    assert_eq!(results_tuple.0, [-62.3].to_vec());
     assert_eq!(
-        results_tuple.1,
-        [43.3, 51.7, 65.43, 67.23, 67.9, 71.02].to_vec()
+        outlier_identifier.clone().get_outliers().unwrap().2,
+        [].to_vec()
+    );
+    assert_eq!(
+        outlier_identifier.get_outliers_detrended(4).unwrap(),
+        [10].to_vec()
     );
-    assert_eq!(results_tuple.2, [].to_vec());
 }
 
 #[test]
-fn negative_k_value_error() {
-    let data = [30.0].to_vec();
-    let outlier_identifier = OutlierIdentifier::new(data, true).with_k_value(-3.0);
-    let results_tuple = outlier_identifier.get_outliers();
+fn get_outliers_detrended_endpoints() {
+    let noise = [0.0, 1.5, -1.0, 0.5, -1.5, 1.0];
+    let mut data: Vec<f64> = (0..20)
+        .map(|i| 10.0 + 2.0 * i as f64 + noise[i % noise.len()])
+        .collect();
+    let outlier_identifier = OutlierIdentifier::new(data.clone(), false);
 
-    assert!(matches!(results_tuple, Err(OutlierError::NegativeKValue)));
+    assert_eq!(
+        outlier_identifier.get_outliers_detrended(4).unwrap(),
+        [].to_vec()
+    );
+
+    data[0] -= 15.0;
+    data[19] += 15.0;
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(
+        outlier_identifier.get_outliers_detrended(4).unwrap(),
+        [0, 19].to_vec()
+    );
 }
 
 #[test]
-fn has_outliers_false() {
-    let data: Vec<f64> = [1.0, 2.0, 4.0, 10.0].to_vec();
-    let has_outliers = OutlierIdentifier::new(data, true).has_outliers().unwrap();
+fn hampel_filter_1() {
+    let data = [1.0, 1.1, 0.9, 1.0, 10.0, 1.0, 1.05, 0.95, 1.0].to_vec();
 
-    assert!(!has_outliers);
+    assert_eq!(
+        OutlierIdentifier::new(data, false)
+            .hampel_filter(2, 3.0)
+            .unwrap(),
+        [4].to_vec()
+    );
 }
 
 #[test]
-fn has_outliers_true() {
-    let data = [-62.3, 67.9, 71.02, 43.3, 51.7, 65.43, 67.23].to_vec();
-    let has_outliers = OutlierIdentifier::new(data, false).has_outliers().unwrap();
+fn hampel_filter_invalid_window_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
 
-    assert!(has_outliers);
+    assert_eq!(
+        OutlierIdentifier::new(data, true).hampel_filter(0, 3.0),
+        Err(OutlierError::InvalidWindow)
+    );
+}
+
+#[test]
+fn get_outliers_detrended_invalid_period_error() {
+    let data = [1.0, 2.0, 3.0].to_vec();
+
+    assert_eq!(
+        OutlierIdentifier::new(data, true).get_outliers_detrended(1),
+        Err(OutlierError::InvalidPeriod)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "`data_is_sorted` was `true`")]
+fn get_outliers_data_is_sorted_but_unsorted_panics() {
+    let data = [3.0, 1.0, 2.0].to_vec();
+    let _ = OutlierIdentifier::new(data, true).get_outliers();
+}
+
+#[test]
+fn trimmed_1() {
+    let data = [
+        -40.0, 10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let outlier_identifier = OutlierIdentifier::new(data, false);
+
+    assert_eq!(
+        outlier_identifier.clone().trimmed().unwrap(),
+        outlier_identifier.get_outliers().unwrap().1
+    );
+}
+
+#[test]
+fn total_order_1() {
+    assert_eq!(total_order(&f64::NAN, &f64::INFINITY), Ordering::Greater);
+    assert_eq!(total_order(&1.0, &f64::NAN), Ordering::Less);
+    assert_eq!(total_order(&-0.0, &0.0), Ordering::Equal);
+    // Both values round to the same `f64`, but are still ordered exactly
+    assert_eq!(total_order(&u64::MAX, &(u64::MAX - 1)), Ordering::Greater);
 }
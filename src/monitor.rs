@@ -0,0 +1,119 @@
+use crate::{OutlierError, OutlierIdentifier};
+
+/// Monitors a sequence of data batches for a change in the rate of outliers.  Each observed batch
+/// has its outliers identified and counted, and the count is compared against the mean and
+/// standard deviation of the counts of all previously observed batches.  A batch whose count is
+/// more than `threshold` standard deviations from the historical mean is itself an outlier, which
+/// signals that the rate of anomalies has drifted.
+pub struct DriftMonitor {
+    k_value: f64,
+    threshold: f64,
+    batch_count: usize,
+    mean: f64,
+    sum_of_squared_deviations: f64,
+}
+
+impl DriftMonitor {
+    /// Creates a new `DriftMonitor` with no history.  The default `k_value`, used to identify the
+    /// outliers within each batch, is `1.5`, and the default `threshold` is `3.0`.
+    pub fn new() -> DriftMonitor {
+        DriftMonitor {
+            k_value: 1.5,
+            threshold: 3.0,
+            batch_count: 0,
+            mean: 0.0,
+            sum_of_squared_deviations: 0.0,
+        }
+    }
+
+    /// Allows for altering the `k_value` used to identify the outliers within each batch.
+    pub fn with_k_value(self, k_value: f64) -> DriftMonitor {
+        DriftMonitor { k_value, ..self }
+    }
+
+    /// Allows for altering the number of standard deviations that a batch's outlier count must
+    /// differ from the historical mean by to be considered anomalous.
+    pub fn with_threshold(self, threshold: f64) -> DriftMonitor {
+        DriftMonitor { threshold, ..self }
+    }
+
+    /// Counts the outliers in `data` and indicates whether that count is anomalous relative to the
+    /// batches observed so far.  At least two batches must have been observed before a count can be
+    /// judged, so the first two calls always return `false`.  If every previous batch had the same
+    /// count, any different count is anomalous.  The count is added to the history afterwards,
+    /// whether or not it was anomalous.  `observe_batch()` returns an `Err` under the same
+    /// conditions as `get_outliers()`, in which case the history is left unchanged.
+    pub fn observe_batch(&mut self, data: &[f64]) -> Result<bool, OutlierError> {
+        let (lower_fence, upper_fence) = OutlierIdentifier::new(data.to_vec(), false)
+            .with_k_value(self.k_value)
+            .get_fences()?;
+
+        let outlier_count = data
+            .iter()
+            .filter(|&&value| value < lower_fence || value > upper_fence)
+            .count() as f64;
+
+        let deviation = (outlier_count - self.mean).abs();
+        let is_anomalous = self.batch_count >= 2 && deviation > self.threshold * self.std_dev();
+
+        // Welford's online algorithm
+        self.batch_count += 1;
+        let delta = outlier_count - self.mean;
+        self.mean += delta / self.batch_count as f64;
+        self.sum_of_squared_deviations += delta * (outlier_count - self.mean);
+
+        Ok(is_anomalous)
+    }
+
+    /// The mean outlier count of the observed batches, or `0.0` if none have been observed.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The sample standard deviation of the outlier counts of the observed batches, or `0.0` if
+    /// fewer than two have been observed.
+    pub fn std_dev(&self) -> f64 {
+        if self.batch_count < 2 {
+            return 0.0;
+        }
+
+        (self.sum_of_squared_deviations / (self.batch_count - 1) as f64).sqrt()
+    }
+}
+
+impl Default for DriftMonitor {
+    fn default() -> DriftMonitor {
+        DriftMonitor::new()
+    }
+}
+
+#[test]
+fn drift_monitor_detects_change_in_outlier_rate() {
+    let calm_batches = [
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 50.0],
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 50.0],
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+    ];
+    let mut drift_monitor = DriftMonitor::new();
+
+    for batch in calm_batches.iter() {
+        assert!(!drift_monitor.observe_batch(batch).unwrap());
+    }
+
+    let noisy_batch = [
+        -90.0, -80.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 80.0, 90.0,
+    ];
+
+    assert!(drift_monitor.observe_batch(&noisy_batch).unwrap());
+    assert_eq!(drift_monitor.mean(), 1.2);
+}
+
+#[test]
+fn drift_monitor_nan_error_leaves_history_unchanged() {
+    let mut drift_monitor = DriftMonitor::new();
+    let result = drift_monitor.observe_batch(&[1.0, f64::NAN]);
+
+    assert!(matches!(result, Err(OutlierError::ContainsNans)));
+    assert_eq!(drift_monitor.mean(), 0.0);
+}
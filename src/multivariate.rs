@@ -0,0 +1,156 @@
+use crate::{outlier_indices, OutlierError};
+
+/// Identifies outliers in two-dimensional data, such as complex numbers stored as `(real, imag)`
+/// pairs or 2D vectors, by their magnitude.  The Euclidean magnitude of each point is computed and
+/// the outlier identification is performed on the magnitudes using `k_value`.  The indices of the
+/// points whose magnitude is an outlier are returned in ascending order.  `magnitude_outliers()`
+/// will return an `Err` if any coordinate is `NAN` or if `k_value` is negative.
+pub fn magnitude_outliers(data: &[(f64, f64)], k_value: f64) -> Result<Vec<usize>, OutlierError> {
+    let magnitudes: Vec<f64> = data.iter().map(|&(x, y)| x.hypot(y)).collect();
+    outlier_indices(&magnitudes, k_value)
+}
+
+/// Identifies outliers in paired data, such as `(x, y)` measurements, by their Mahalanobis
+/// distance from the centroid, which accounts for the correlation between the two coordinates.  A
+/// point can be an outlier in the joint distribution, such as one that lies far from the line the
+/// other points follow, even when neither of its coordinates is an outlier on its own.
+pub struct PairedOutlierIdentifier {
+    data_set: Vec<(f64, f64)>,
+}
+
+impl PairedOutlierIdentifier {
+    /// Creates a new `PairedOutlierIdentifier` for `data_set`.
+    pub fn from_pairs(data_set: Vec<(f64, f64)>) -> PairedOutlierIdentifier {
+        PairedOutlierIdentifier { data_set }
+    }
+
+    /// Returns the indices of the points whose Mahalanobis distance from the centroid exceeds
+    /// `threshold`, in ascending order.  The distance of a point `p` is `sqrt((p - c)' S^-1 (p - c))`,
+    /// where `c` is the centroid and `S` is the sample covariance matrix of the data set, so a
+    /// `threshold` of `3.0` flags points more than three standard deviations from the centroid,
+    /// along whichever direction they lie in.  The centroid and covariance are computed from every
+    /// point, outliers included, so a large share of outliers can mask one another.
+    /// `get_outliers_mahalanobis()` will return an `Err` if any coordinate is `NAN`, if the data set
+    /// has fewer than `3` points, or if the covariance matrix is singular, such as when every point
+    /// lies on a single line.
+    pub fn get_outliers_mahalanobis(&self, threshold: f64) -> Result<Vec<usize>, OutlierError> {
+        if self.data_set.iter().any(|(x, y)| x.is_nan() || y.is_nan()) {
+            return Err(OutlierError::ContainsNans);
+        }
+
+        if self.data_set.len() < 3 {
+            return Err(OutlierError::InsufficientData {
+                required: 3,
+                found: self.data_set.len(),
+            });
+        }
+
+        let count = self.data_set.len() as f64;
+        let mean_x = self.data_set.iter().map(|&(x, _)| x).sum::<f64>() / count;
+        let mean_y = self.data_set.iter().map(|&(_, y)| y).sum::<f64>() / count;
+        let (mut variance_x, mut variance_y, mut covariance) = (0.0, 0.0, 0.0);
+
+        for &(x, y) in &self.data_set {
+            variance_x += (x - mean_x) * (x - mean_x);
+            variance_y += (y - mean_y) * (y - mean_y);
+            covariance += (x - mean_x) * (y - mean_y);
+        }
+
+        variance_x /= count - 1.0;
+        variance_y /= count - 1.0;
+        covariance /= count - 1.0;
+
+        // The determinant is compared relative to the variances, so that the check doesn't depend
+        // on the scale of the data, and perfectly correlated points, whose determinant is only zero
+        // up to rounding, are caught
+        let determinant = variance_x * variance_y - covariance * covariance;
+
+        if !determinant.is_finite() || determinant <= 1e-12 * variance_x * variance_y {
+            return Err(OutlierError::SingularCovariance);
+        }
+
+        Ok(self
+            .data_set
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(x, y))| {
+                let (dx, dy) = (x - mean_x, y - mean_y);
+                let squared_distance = (variance_y * dx * dx - 2.0 * covariance * dx * dy
+                    + variance_x * dy * dy)
+                    / determinant;
+
+                squared_distance.sqrt() > threshold
+            })
+            .map(|(index, _)| index)
+            .collect())
+    }
+}
+
+#[test]
+fn magnitude_outliers_1() {
+    let data = [
+        (1.0, 0.0),
+        (0.0, 1.1),
+        (-0.9, 0.3),
+        (0.6, -0.8),
+        (0.0, -1.2),
+        (-8.0, 6.0),
+        (0.7, 0.7),
+        (1.0, 0.2),
+    ];
+    let indices = magnitude_outliers(&data, 1.5).unwrap();
+
+    assert_eq!(indices, [5].to_vec());
+}
+
+#[test]
+fn magnitude_outliers_nan_error() {
+    let indices = magnitude_outliers(&[(1.0, f64::NAN)], 1.5);
+
+    assert!(matches!(indices, Err(OutlierError::ContainsNans)));
+}
+
+#[test]
+fn get_outliers_mahalanobis_1() {
+    // The last point is within the range of both coordinates, but far from the line the others
+    // follow
+    let data = [
+        (1.0, 1.2),
+        (2.0, 1.9),
+        (3.0, 3.1),
+        (4.0, 4.0),
+        (5.0, 4.8),
+        (6.0, 6.2),
+        (7.0, 7.1),
+        (8.0, 7.9),
+        (9.0, 9.0),
+        (10.0, 10.1),
+        (3.0, 8.0),
+    ];
+    let outlier_identifier = PairedOutlierIdentifier::from_pairs(data.to_vec());
+
+    assert_eq!(
+        outlier_identifier.get_outliers_mahalanobis(2.5).unwrap(),
+        [10].to_vec()
+    );
+    assert!(magnitude_outliers(&data, 1.5).unwrap().is_empty());
+}
+
+#[test]
+fn get_outliers_mahalanobis_degenerate_errors() {
+    let too_few = PairedOutlierIdentifier::from_pairs([(1.0, 2.0), (3.0, 4.0)].to_vec());
+    let collinear =
+        PairedOutlierIdentifier::from_pairs([(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)].to_vec());
+
+    assert!(matches!(
+        too_few.get_outliers_mahalanobis(3.0),
+        Err(OutlierError::InsufficientData {
+            required: 3,
+            found: 2
+        })
+    ));
+    assert!(matches!(
+        collinear.get_outliers_mahalanobis(3.0),
+        Err(OutlierError::SingularCovariance)
+    ));
+}
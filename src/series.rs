@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+
+use crate::{outlier_indices, quartiles, OutlierError};
+
+/// Identifies anomalous price movements in a financial time series.  The log return of each day,
+/// `ln(prices[i] / prices[i - 1])`, is computed and the outlier identification is performed on
+/// those returns using `k_value`.  The indices into `prices` of the days whose return is an
+/// outlier are returned in ascending order.  `log_return_outliers()` will return an `Err` if
+/// `prices` contains one or more `NAN`s or non-positive values, or if `k_value` is negative.
+pub fn log_return_outliers(prices: &[f64], k_value: f64) -> Result<Vec<usize>, OutlierError> {
+    if prices.iter().any(|price| price.is_nan()) {
+        return Err(OutlierError::ContainsNans);
+    }
+
+    if prices.iter().any(|&price| price <= 0.0) {
+        return Err(OutlierError::ContainsNonPositiveValues);
+    }
+
+    let log_returns: Vec<f64> = prices
+        .windows(2)
+        .map(|window| (window[1] / window[0]).ln())
+        .collect();
+
+    let indices = outlier_indices(&log_returns, k_value)?;
+
+    // The first return is that of the second day
+    Ok(indices.into_iter().map(|index| index + 1).collect())
+}
+
+/// Identifies outliers relative to their local density, for data whose spread varies across its
+/// range, where a single pair of fences would mask local anomalies.  For each value, the fences are
+/// computed, using `k_value`, from only its `neighborhood` nearest values (excluding itself), and
+/// the value is flagged if it lies outside of them.  Ties in distance are broken in favor of the
+/// smaller neighbor.  If `series` has fewer than `neighborhood + 1` values, every other value is a
+/// neighbor, and if it has fewer than three values, nothing is flagged.  The indices of the flagged
+/// values are returned in ascending order.  `local_outliers()` will return an `Err` if `series`
+/// contains one or more `NAN`s, if `k_value` is negative, or if `neighborhood` is less than `2`.
+pub fn local_outliers(
+    series: &[f64],
+    neighborhood: usize,
+    k_value: f64,
+) -> Result<Vec<usize>, OutlierError> {
+    if series.iter().any(|x| x.is_nan()) {
+        return Err(OutlierError::ContainsNans);
+    }
+
+    if k_value < 0.0 {
+        return Err(OutlierError::NegativeKValue);
+    }
+
+    if neighborhood < 2 {
+        return Err(OutlierError::InvalidNeighborhoodSize);
+    }
+
+    // Fewer than two neighbors have infinite fences, as any data set of fewer than two values does
+    if series.len() < 3 {
+        return Ok(Vec::new());
+    }
+
+    let mut sorted_indices: Vec<usize> = (0..series.len()).collect();
+    sorted_indices.sort_by(|&a, &b| series[a].total_cmp(&series[b]));
+    let sorted_series: Vec<f64> = sorted_indices.iter().map(|&index| series[index]).collect();
+
+    let neighborhood = neighborhood.min(series.len() - 1);
+    let mut indices = Vec::new();
+    let mut neighbors = Vec::with_capacity(neighborhood);
+
+    for (position, &value) in sorted_series.iter().enumerate() {
+        // The nearest neighbors by value form a contiguous window around the value's position in
+        // the sorted series
+        let mut start = position;
+        let mut end = position + 1;
+
+        while end - start - 1 < neighborhood {
+            let can_extend_start = start > 0;
+            let can_extend_end = end < sorted_series.len();
+            let extend_start = match (can_extend_start, can_extend_end) {
+                (true, true) => value - sorted_series[start - 1] <= sorted_series[end] - value,
+                (can_extend_start, _) => can_extend_start,
+            };
+
+            if extend_start {
+                start -= 1;
+            } else {
+                end += 1;
+            }
+        }
+
+        neighbors.clear();
+        neighbors.extend_from_slice(&sorted_series[start..position]);
+        neighbors.extend_from_slice(&sorted_series[position + 1..end]);
+
+        let (lower_quartile, upper_quartile) = quartiles(&neighbors);
+        let quartile_adjustment_value = k_value * (upper_quartile - lower_quartile);
+
+        if value < lower_quartile - quartile_adjustment_value
+            || value > upper_quartile + quartile_adjustment_value
+        {
+            indices.push(sorted_indices[position]);
+        }
+    }
+
+    indices.sort_unstable();
+
+    Ok(indices)
+}
+
+/// Identifies deviations from a known expected value at each index, such as a ramp or a schedule.
+/// The residual of each value, `series[i] - expected(i)`, is computed and the outlier
+/// identification is performed on the residuals using `k_value`.  The indices of the values whose
+/// residual is an outlier are returned in ascending order.  `residual_outliers()` will return an
+/// `Err` if any residual is `NAN` or if `k_value` is negative.
+pub fn residual_outliers(
+    series: &[f64],
+    expected: impl Fn(usize) -> f64,
+    k_value: f64,
+) -> Result<Vec<usize>, OutlierError> {
+    let residuals: Vec<f64> = series
+        .iter()
+        .enumerate()
+        .map(|(index, value)| value - expected(index))
+        .collect();
+
+    outlier_indices(&residuals, k_value)
+}
+
+/// The direction that a monotonic series is expected to move in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+/// Identifies the values of a series that violate its expected monotonicity, such as a cumulative
+/// counter that resets or decreases because of corrupted data.  This is a structural check,
+/// independent of the magnitude of the values.  The value at index `i` is flagged if it moves
+/// against `direction` relative to the value at `i - 1`; repeated values don't violate either
+/// direction, and any comparison involving a `NAN` is a violation.  The indices of the flagged
+/// values are returned in ascending order, so they can be merged with the indices returned by the
+/// statistical methods or passed to `coalesce_outliers()`.
+pub fn monotonic_outliers(series: &[f64], direction: Direction) -> Vec<usize> {
+    series
+        .windows(2)
+        .enumerate()
+        .filter(|(_, window)| match direction {
+            Direction::Increasing => matches!(
+                window[1].partial_cmp(&window[0]),
+                None | Some(Ordering::Less)
+            ),
+            Direction::Decreasing => matches!(
+                window[1].partial_cmp(&window[0]),
+                None | Some(Ordering::Greater)
+            ),
+        })
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// Merges flagged indices, such as those returned by `log_return_outliers()`, into distinct
+/// events.  Two flagged indices belong to the same event if at most `max_gap` unflagged indices lie
+/// between them, so a `max_gap` of `0` only merges adjacent indices.  Each event is returned as an
+/// inclusive `(start, end)` range, in ascending order.  `flagged` doesn't need to be sorted, and
+/// duplicate indices are ignored.
+pub fn coalesce_outliers(flagged: &[usize], max_gap: usize) -> Vec<(usize, usize)> {
+    let mut flagged = flagged.to_vec();
+    flagged.sort_unstable();
+    flagged.dedup();
+
+    let mut events: Vec<(usize, usize)> = Vec::new();
+
+    for index in flagged {
+        match events.last_mut() {
+            Some((_, end)) if index - *end - 1 <= max_gap => *end = index,
+            _ => events.push((index, index)),
+        }
+    }
+
+    events
+}
+
+#[test]
+fn log_return_outliers_1() {
+    let prices = [
+        100.0, 101.0, 102.0, 103.0, 104.0, 105.0, 140.0, 141.0, 142.0, 143.0,
+    ];
+    let indices = log_return_outliers(&prices, 1.5).unwrap();
+
+    assert_eq!(indices, [6].to_vec());
+}
+
+#[test]
+fn log_return_outliers_non_positive_error() {
+    let prices = [100.0, 0.0, 102.0];
+    let indices = log_return_outliers(&prices, 1.5);
+
+    assert!(matches!(
+        indices,
+        Err(OutlierError::ContainsNonPositiveValues)
+    ));
+}
+
+#[test]
+fn local_outliers_1() {
+    // The anomaly at index 3 lies within the global spread, but is far from its neighbors
+    let series = [
+        1.0, 1.1, 1.2, 5.0, 1.3, 1.4, 100.0, 110.0, 120.0, 130.0, 140.0,
+    ];
+    let indices = local_outliers(&series, 4, 1.5).unwrap();
+
+    assert_eq!(indices, [3].to_vec());
+}
+
+#[test]
+fn local_outliers_small_series() {
+    assert_eq!(local_outliers(&[1.0, 2.0], 2, 1.5), Ok(Vec::new()));
+    assert_eq!(local_outliers(&[], 2, 1.5), Ok(Vec::new()));
+}
+
+#[test]
+fn local_outliers_invalid_neighborhood_size_error() {
+    let indices = local_outliers(&[1.0, 2.0, 3.0], 1, 1.5);
+
+    assert!(matches!(
+        indices,
+        Err(OutlierError::InvalidNeighborhoodSize)
+    ));
+}
+
+#[test]
+fn coalesce_outliers_1() {
+    let flagged = [40, 3, 4, 6, 20, 21, 9, 4];
+
+    assert_eq!(
+        coalesce_outliers(&flagged, 0),
+        [(3, 4), (6, 6), (9, 9), (20, 21), (40, 40)].to_vec()
+    );
+    assert_eq!(
+        coalesce_outliers(&flagged, 2),
+        [(3, 9), (20, 21), (40, 40)].to_vec()
+    );
+}
+
+#[test]
+fn monotonic_outliers_increasing() {
+    let series = [1.0, 2.0, 2.0, 5.0, 0.0, 1.0, 3.0, f64::NAN, 4.0];
+
+    assert_eq!(
+        monotonic_outliers(&series, Direction::Increasing),
+        [4, 7, 8].to_vec()
+    );
+}
+
+#[test]
+fn monotonic_outliers_decreasing() {
+    let series = [5.0, 4.0, 4.5, 3.0, 1.0];
+
+    assert_eq!(
+        monotonic_outliers(&series, Direction::Decreasing),
+        [2].to_vec()
+    );
+}
+
+#[test]
+fn residual_outliers_1() {
+    // A ramp with small noise and a spike that is unremarkable relative to the whole series
+    let series = [0.1, 2.0, 3.9, 6.1, 8.0, 10.0, 18.0, 14.1, 15.9, 18.0, 20.1];
+    let indices = residual_outliers(&series, |index| 2.0 * index as f64, 1.5).unwrap();
+
+    assert_eq!(indices, [6].to_vec());
+}
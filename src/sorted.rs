@@ -0,0 +1,182 @@
+use crate::{round_to_precision, FenceBasis, Fences, OutlierError};
+
+/// A data set that has been sorted, and whose quartiles have been computed, once, so that the
+/// fences and partitions at many `k_value`s can be evaluated cheaply, such as for a sensitivity
+/// sweep.  A `SortedData` is created by `OutlierIdentifier::into_sorted_data()`.
+pub struct SortedData {
+    data_set: Vec<f64>,
+    fence_basis: FenceBasis,
+    log_transform: bool,
+    inclusive_fences: bool,
+    output_precision: Option<u32>,
+}
+
+impl SortedData {
+    pub(crate) fn new(
+        data_set: Vec<f64>,
+        fence_basis: FenceBasis,
+        log_transform: bool,
+        inclusive_fences: bool,
+        output_precision: Option<u32>,
+    ) -> SortedData {
+        SortedData {
+            data_set,
+            fence_basis,
+            log_transform,
+            inclusive_fences,
+            output_precision,
+        }
+    }
+
+    /// Returns the fences at `k_value`, which replaces the one that the detection method would
+    /// otherwise use.  As with `get_outliers()`, the fences of a data set of fewer than two values
+    /// are infinite.  The fences are rounded if `with_output_precision()` was used.  `fences_at()`
+    /// will return an `Err` if `k_value` is negative.
+    pub fn fences_at(&self, k_value: f64) -> Result<Fences, OutlierError> {
+        let (lower_fence, upper_fence) = self.unrounded_fences_at(k_value)?;
+
+        Ok(Fences {
+            lower: round_to_precision(lower_fence, self.output_precision),
+            upper: round_to_precision(upper_fence, self.output_precision),
+        })
+    }
+
+    /// Partitions the data set into its lower outliers, non-outliers, and upper outliers at
+    /// `k_value`, as `get_outliers()` would.  Since the data set is already sorted, the partitions
+    /// are found with a binary search, rather than by classifying every value.  `partition_at()`
+    /// will return an `Err` if `k_value` is negative.
+    #[allow(clippy::type_complexity)]
+    pub fn partition_at(
+        &self,
+        k_value: f64,
+    ) -> Result<(Vec<f64>, Vec<f64>, Vec<f64>), OutlierError> {
+        let (lower_fence, upper_fence) = self.unrounded_fences_at(k_value)?;
+        // Coinciding fences are exclusive regardless, as they are for `get_outliers()`
+        let inclusive_fences = self.inclusive_fences && lower_fence < upper_fence;
+        let non_outliers_start = if inclusive_fences {
+            self.data_set.partition_point(|&data| data <= lower_fence)
+        } else {
+            self.data_set.partition_point(|&data| data < lower_fence)
+        };
+        let upper_outliers_start = if inclusive_fences {
+            self.data_set.partition_point(|&data| data < upper_fence)
+        } else {
+            self.data_set.partition_point(|&data| data <= upper_fence)
+        }
+        .max(non_outliers_start);
+
+        let round = |values: &[f64]| {
+            values
+                .iter()
+                .map(|&data| round_to_precision(data, self.output_precision))
+                .collect()
+        };
+
+        Ok((
+            round(&self.data_set[..non_outliers_start]),
+            round(&self.data_set[non_outliers_start..upper_outliers_start]),
+            round(&self.data_set[upper_outliers_start..]),
+        ))
+    }
+
+    fn unrounded_fences_at(&self, k_value: f64) -> Result<(f64, f64), OutlierError> {
+        if k_value < 0.0 {
+            return Err(OutlierError::NegativeKValue);
+        }
+
+        // As for `get_outliers()`, no value of a data set of fewer than two values is an outlier
+        if self.data_set.len() < 2 {
+            return Ok((f64::NEG_INFINITY, f64::INFINITY));
+        }
+
+        let adjustment_value = k_value * self.fence_basis.spread;
+        let lower_fence = self.fence_basis.lower_anchor - adjustment_value;
+        let upper_fence = self.fence_basis.upper_anchor + adjustment_value;
+
+        if self.log_transform {
+            Ok((lower_fence.exp(), upper_fence.exp()))
+        } else {
+            Ok((lower_fence, upper_fence))
+        }
+    }
+}
+
+#[test]
+fn partition_at_1() {
+    use crate::OutlierIdentifier;
+
+    let data = [
+        10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 17.0, 12.0, 22.0, 14.0, 11.0,
+    ]
+    .to_vec();
+    let sorted_data = OutlierIdentifier::new(data.clone(), false)
+        .into_sorted_data()
+        .unwrap();
+
+    for k_value in [0.0, 0.5, 1.0, 1.5, 3.0] {
+        let outlier_identifier = OutlierIdentifier::new(data.clone(), false).with_k_value(k_value);
+        let (lower_fence, upper_fence) = outlier_identifier.fences().unwrap();
+
+        assert_eq!(
+            sorted_data.fences_at(k_value).unwrap(),
+            Fences {
+                lower: lower_fence,
+                upper: upper_fence
+            }
+        );
+        assert_eq!(
+            sorted_data.partition_at(k_value).unwrap(),
+            outlier_identifier.get_outliers().unwrap()
+        );
+    }
+
+    assert!(matches!(
+        sorted_data.partition_at(-1.0),
+        Err(OutlierError::NegativeKValue)
+    ));
+}
+
+#[test]
+fn partition_at_inclusive_fences() {
+    use crate::{OutlierIdentifier, QuartileMethod};
+
+    // Tukey's hinges of 1 through 9 are 3 and 7, so a `k_value` of 0 puts both on a value
+    let outlier_identifier = OutlierIdentifier::new((1..=9).map(|i| i as f64).collect(), true)
+        .with_quartile_method(QuartileMethod::Tukey)
+        .with_inclusive_fences(true);
+    let sorted_data = outlier_identifier.clone().into_sorted_data().unwrap();
+
+    assert_eq!(
+        sorted_data.partition_at(0.0).unwrap(),
+        (
+            [1.0, 2.0, 3.0].to_vec(),
+            [4.0, 5.0, 6.0].to_vec(),
+            [7.0, 8.0, 9.0].to_vec()
+        )
+    );
+    assert_eq!(
+        sorted_data.partition_at(0.0).unwrap(),
+        outlier_identifier.with_k_value(0.0).get_outliers().unwrap()
+    );
+}
+
+#[test]
+fn partition_at_small_data_set() {
+    use crate::OutlierIdentifier;
+
+    let outlier_identifier =
+        OutlierIdentifier::new([5.0].to_vec(), true).with_inclusive_fences(true);
+    let sorted_data = outlier_identifier.clone().into_sorted_data().unwrap();
+
+    assert_eq!(
+        sorted_data.fences_at(1.5).unwrap(),
+        Fences {
+            lower: f64::NEG_INFINITY,
+            upper: f64::INFINITY
+        }
+    );
+    assert_eq!(
+        sorted_data.partition_at(0.0).unwrap(),
+        outlier_identifier.get_outliers().unwrap()
+    );
+}
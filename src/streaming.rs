@@ -0,0 +1,164 @@
+use std::collections::VecDeque;
+
+use crate::{Classification, Fences, OutlierIdentifier};
+
+/// Identifies outliers in a stream of values, using a sliding window of the most recent values as
+/// the reference data.  Each pushed value is classified against the fences of the values currently
+/// in the window, and then added to the window, evicting the oldest value once the window is full.
+/// Only the window is held in memory, so the stream can be arbitrarily long.
+#[derive(Debug, Clone)]
+pub struct StreamingOutlierDetector {
+    window: VecDeque<f64>,
+    window_size: usize,
+    k_value: f64,
+    // The fences of the full window, which are recomputed whenever the window changes
+    fences: Option<Fences>,
+}
+
+impl StreamingOutlierDetector {
+    /// Creates a new `StreamingOutlierDetector` with an empty window that holds up to `window_size`
+    /// values, which is at least `1`, and computes its fences with `k_value`.
+    pub fn new(window_size: usize, k_value: f64) -> StreamingOutlierDetector {
+        let window_size = window_size.max(1);
+
+        StreamingOutlierDetector {
+            window: VecDeque::with_capacity(window_size),
+            window_size,
+            k_value,
+            fences: None,
+        }
+    }
+
+    /// Classifies `value` against the fences of the current window, and then adds it to the window.
+    /// The fences are recomputed whenever the window changes.  Until the window is full, values
+    /// are added without being classified, and `None` is returned.  A `NAN` is never added to the
+    /// window, and `None` is returned for it, as it is for every value if the `k_value` is negative.
+    /// An infinity is classified like any other value, but is never added to the window either, so
+    /// that it can't keep the fences from being computed for the values that follow it.
+    pub fn push(&mut self, value: f64) -> Option<Classification> {
+        if value.is_nan() {
+            return None;
+        }
+
+        let classification = self
+            .fences
+            .map(|fences| Classification::new(value, fences.lower, fences.upper));
+
+        if value.is_finite() {
+            if self.window.len() == self.window_size {
+                self.window.pop_front();
+            }
+
+            self.window.push_back(value);
+
+            if self.window.len() == self.window_size {
+                self.fences = OutlierIdentifier::new(self.window.iter().cloned().collect(), false)
+                    .with_k_value(self.k_value)
+                    .get_fences()
+                    .ok()
+                    .map(|(lower_fence, upper_fence)| Fences {
+                        lower: lower_fence,
+                        upper: upper_fence,
+                    });
+            }
+        }
+
+        classification
+    }
+
+    /// Returns a copy of the fences that the next pushed value will be classified against, such as
+    /// for displaying the current acceptance band, without recomputing them.  Since `push()` takes
+    /// `&mut self`, a snapshot reflects every value pushed before it and none pushed after it;
+    /// another thread can read the fences from a clone, or by taking a snapshot under the lock that
+    /// guards `push()`.  Until the window is full, or if the `k_value` is negative, the fences are
+    /// infinite.
+    pub fn snapshot(&self) -> Fences {
+        self.fences.unwrap_or(Fences {
+            lower: f64::NEG_INFINITY,
+            upper: f64::INFINITY,
+        })
+    }
+}
+
+#[test]
+fn streaming_outlier_detector_1() {
+    let mut streaming_outlier_detector = StreamingOutlierDetector::new(8, 1.5);
+
+    for value in [10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0] {
+        assert_eq!(streaming_outlier_detector.push(value), None);
+    }
+
+    assert_eq!(streaming_outlier_detector.push(f64::NAN), None);
+    assert_eq!(streaming_outlier_detector.push(12.0), None);
+    assert_eq!(
+        streaming_outlier_detector.push(30.0),
+        Some(Classification::UpperOutlier)
+    );
+    assert_eq!(
+        streaming_outlier_detector.push(13.0),
+        Some(Classification::NonOutlier)
+    );
+    assert_eq!(
+        streaming_outlier_detector.push(-5.0),
+        Some(Classification::LowerOutlier)
+    );
+}
+
+#[test]
+fn streaming_outlier_detector_infinity() {
+    let mut streaming_outlier_detector = StreamingOutlierDetector::new(8, 1.5);
+
+    for value in [10.0, 12.0, 11.0, 15.0, 11.0, 14.0, 13.0, 12.0] {
+        assert_eq!(streaming_outlier_detector.push(value), None);
+    }
+
+    assert_eq!(
+        streaming_outlier_detector.push(f64::INFINITY),
+        Some(Classification::UpperOutlier)
+    );
+    assert_eq!(
+        streaming_outlier_detector.push(100.0),
+        Some(Classification::UpperOutlier)
+    );
+    assert_eq!(
+        streaming_outlier_detector.push(f64::NEG_INFINITY),
+        Some(Classification::LowerOutlier)
+    );
+}
+
+#[test]
+fn streaming_outlier_detector_snapshot() {
+    let mut streaming_outlier_detector = StreamingOutlierDetector::new(4, 1.5);
+
+    for value in [1.0, 2.0, 3.0] {
+        streaming_outlier_detector.push(value);
+
+        assert_eq!(
+            streaming_outlier_detector.snapshot(),
+            Fences {
+                lower: f64::NEG_INFINITY,
+                upper: f64::INFINITY,
+            }
+        );
+    }
+
+    streaming_outlier_detector.push(4.0);
+    let (lower_fence, upper_fence) = OutlierIdentifier::new([1.0, 2.0, 3.0, 4.0].to_vec(), false)
+        .fences()
+        .unwrap();
+    let snapshot = streaming_outlier_detector.snapshot();
+
+    assert_eq!(
+        snapshot,
+        Fences {
+            lower: lower_fence,
+            upper: upper_fence,
+        }
+    );
+
+    // Values that never enter the window leave the fences unchanged
+    streaming_outlier_detector.push(f64::NAN);
+    streaming_outlier_detector.push(f64::INFINITY);
+
+    assert_eq!(streaming_outlier_detector.clone().snapshot(), snapshot);
+}
@@ -0,0 +1,188 @@
+use crate::OutlierError;
+
+/// Identifies outliers in weighted data, such as a histogram whose values each occurred a number
+/// of times, without expanding it into one value per occurrence.  The fences are computed from
+/// weighted quartiles, which, for whole-number weights, are the quartiles that
+/// `OutlierIdentifier` would compute for the expanded data set, so the classification is the same.
+pub struct WeightedOutlierIdentifier {
+    data_set: Vec<(f64, f64)>,
+    k_value: f64,
+}
+
+impl WeightedOutlierIdentifier {
+    /// Creates a new `WeightedOutlierIdentifier` from `(value, weight)` pairs, with the default
+    /// `k_value` of `1.5`.  The same value may appear in more than one pair.
+    pub fn from_weighted(data_set: Vec<(f64, f64)>) -> WeightedOutlierIdentifier {
+        WeightedOutlierIdentifier {
+            data_set,
+            k_value: 1.5,
+        }
+    }
+
+    /// Allows for altering the `k_value`, as `OutlierIdentifier::with_k_value()` does.
+    pub fn with_k_value(self, k_value: f64) -> WeightedOutlierIdentifier {
+        WeightedOutlierIdentifier { k_value, ..self }
+    }
+
+    /// Performs the outlier identification, partitioning the `(value, weight)` pairs into the lower
+    /// outliers, non-outliers, and upper outliers, each in ascending order of value.
+    /// `get_outliers()` will return an `Err` if any value is `NAN` or infinite, if any weight is
+    /// negative or `NAN`, or if the `k_value` is negative.
+    #[allow(clippy::type_complexity)]
+    pub fn get_outliers(
+        mut self,
+    ) -> Result<(Vec<(f64, f64)>, Vec<(f64, f64)>, Vec<(f64, f64)>), OutlierError> {
+        let (lower_fence, upper_fence) = self.get_fences()?;
+        let mut lower_outliers = Vec::new();
+        let mut non_outliers = Vec::new();
+        let mut upper_outliers = Vec::new();
+
+        for (value, weight) in self.data_set {
+            if value < lower_fence {
+                lower_outliers.push((value, weight));
+            } else if value > upper_fence {
+                upper_outliers.push((value, weight));
+            } else {
+                non_outliers.push((value, weight));
+            }
+        }
+
+        Ok((lower_outliers, non_outliers, upper_outliers))
+    }
+
+    // Sorts the data set and computes the fences from its weighted quartiles
+    fn get_fences(&mut self) -> Result<(f64, f64), OutlierError> {
+        if self.k_value < 0.0 {
+            return Err(OutlierError::NegativeKValue);
+        }
+
+        if self.data_set.iter().any(|(value, _)| value.is_nan()) {
+            return Err(OutlierError::ContainsNans);
+        }
+
+        if self.data_set.iter().any(|(value, _)| value.is_infinite()) {
+            return Err(OutlierError::ContainsInfinities);
+        }
+
+        if self
+            .data_set
+            .iter()
+            .any(|&(_, weight)| weight.is_nan() || weight < 0.0)
+        {
+            return Err(OutlierError::InvalidWeight);
+        }
+
+        self.data_set.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let lower_quartile = weighted_quantile(&self.data_set, 0.25);
+        let upper_quartile = weighted_quantile(&self.data_set, 0.75);
+        let interquartile_range = upper_quartile - lower_quartile;
+
+        Ok((
+            lower_quartile - self.k_value * interquartile_range,
+            upper_quartile + self.k_value * interquartile_range,
+        ))
+    }
+}
+
+// Uses the same interpolation as `quantile()` (R's type 8), on the data set expanded by its weights,
+// where the value at each rank is found from the cumulative weights rather than by expanding it
+fn weighted_quantile(sorted_data: &[(f64, f64)], tau: f64) -> f64 {
+    let total_weight: f64 = sorted_data.iter().map(|&(_, weight)| weight).sum();
+
+    if total_weight == 0.0 {
+        return f64::NAN;
+    }
+
+    // The value at the 1-based `rank` of the expanded data set
+    let value_at_rank = |rank: f64| {
+        let mut cumulative_weight = 0.0;
+
+        for &(value, weight) in sorted_data {
+            cumulative_weight += weight;
+
+            if cumulative_weight >= rank {
+                return value;
+            }
+        }
+
+        sorted_data[sorted_data.len() - 1].0
+    };
+
+    let h = (total_weight + 1.0 / 3.0) * tau + 1.0 / 3.0;
+    let rank = h.floor();
+
+    if rank < 1.0 {
+        return value_at_rank(1.0);
+    }
+
+    if rank >= total_weight {
+        return value_at_rank(total_weight);
+    }
+
+    let lower = value_at_rank(rank);
+    let upper = value_at_rank(rank + 1.0);
+
+    lower + (h - rank) * (upper - lower)
+}
+
+#[test]
+fn get_outliers_1() {
+    use crate::OutlierIdentifier;
+
+    let data = [
+        (11.0, 3.0),
+        (10.0, 1.0),
+        (12.0, 2.0),
+        (13.0, 1.0),
+        (14.0, 2.0),
+        (15.0, 1.0),
+        (17.0, 1.0),
+        (22.0, 1.0),
+        (16.0, 0.0),
+    ]
+    .to_vec();
+    let expanded_data: Vec<f64> = data
+        .iter()
+        .flat_map(|&(value, weight)| vec![value; weight as usize])
+        .collect();
+
+    let (lower, non_outliers, upper) = WeightedOutlierIdentifier::from_weighted(data)
+        .get_outliers()
+        .unwrap();
+    let (expanded_lower, expanded_non_outliers, expanded_upper) =
+        OutlierIdentifier::new(expanded_data, false)
+            .get_outliers()
+            .unwrap();
+
+    assert!(lower.is_empty() && expanded_lower.is_empty());
+    assert_eq!(upper, [(22.0, 1.0)].to_vec());
+    assert_eq!(expanded_upper, [22.0].to_vec());
+    assert_eq!(
+        non_outliers
+            .iter()
+            .map(|&(value, _)| value)
+            .collect::<Vec<f64>>(),
+        [10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0].to_vec()
+    );
+    assert_eq!(expanded_non_outliers.len(), 11);
+}
+
+#[test]
+fn get_outliers_invalid_weight_error() {
+    let data = [(1.0, 1.0), (2.0, -1.0)].to_vec();
+    let results_tuple = WeightedOutlierIdentifier::from_weighted(data).get_outliers();
+
+    assert!(matches!(results_tuple, Err(OutlierError::InvalidWeight)));
+}
+
+#[test]
+fn get_outliers_infinity_error() {
+    let data = [(1.0, 1.0), (f64::INFINITY, 1.0)].to_vec();
+    let results_tuple = WeightedOutlierIdentifier::from_weighted(data).get_outliers();
+
+    assert!(matches!(
+        results_tuple,
+        Err(OutlierError::ContainsInfinities)
+    ));
+}